@@ -5,6 +5,7 @@ use std::{
     fmt::{self, Display, Formatter},
     io::Error as IoError,
     result,
+    time::Duration,
 };
 use steam_review_api::RevApiError;
 use url::ParseError as UrlParseError;
@@ -16,8 +17,13 @@ pub type Result<T> = result::Result<T, Error>;
 #[derive(Debug)]
 pub enum Error {
     ReviewApi(RevApiError),
-    MultipleAppids,
     NoDataAfterFiltering,
+    /// Steam kept throttling the scraper past its retry budget. `attempts` is how
+    /// many retries were spent and `last` is the final delay that was waited.
+    RateLimited {
+        attempts: u32,
+        last: Duration,
+    },
     Io(IoError),
     Csv(CsvError),
     UrlParse(UrlParseError),
@@ -28,8 +34,8 @@ impl StdError for Error {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
         match *self {
             Error::ReviewApi(ref e) => Some(e),
-            Error::MultipleAppids => None,
             Error::NoDataAfterFiltering => None,
+            Error::RateLimited { .. } => None,
             Error::Io(ref e) => Some(e),
             Error::Csv(ref e) => Some(e),
             Error::UrlParse(ref e) => Some(e),
@@ -44,11 +50,15 @@ impl Display for Error {
 
         match self {
             ReviewApi(e) => e.fmt(f),
-            MultipleAppids => write!(f, "Scraping multiple appids is unsupported."),
             NoDataAfterFiltering => write!(
                 f,
                 "No data were available to write after filtering for duplicates."
             ),
+            RateLimited { attempts, last } => write!(
+                f,
+                "Steam kept rate limiting us; gave up after {} retries (last waited {:?}).",
+                attempts, last
+            ),
             Io(e) => e.fmt(f),
             Csv(e) => e.fmt(f),
             UrlParse(e) => e.fmt(f),
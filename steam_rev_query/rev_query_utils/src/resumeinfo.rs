@@ -1,3 +1,9 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    io::{self, ErrorKind},
+    path::Path,
+};
 use steam_review_api::convenience_structs::{
     flat_query::{FlattenedQuery, TitleSerde},
     UnixTimestamp,
@@ -5,32 +11,63 @@ use steam_review_api::convenience_structs::{
 
 use crate::error::{Error, Result};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ResumeInfo {
     pub appid: TitleSerde,
     pub timestamp: UnixTimestamp,
 }
 
 impl ResumeInfo {
-    pub fn update(&mut self, query: &FlattenedQuery) -> Result<()> {
+    pub fn update(&mut self, query: &FlattenedQuery) {
         // Update the timestamp if the query is older.
         if self.timestamp > query.timestamp_created {
             self.timestamp = query.timestamp_created;
         }
 
-        // I only support resuming from one appid currently.
-        // So replace the appid if it's null or fail if they're different.
+        // One `ResumeInfo` tracks one appid; callers keep a per-appid map (see
+        // `ScraperCache::resume_from_file_partitioned`), so the only thing left to
+        // do here is adopt the appid the first time a row arrives.
         if self.appid.is_default() {
             self.appid = query.appid.clone();
-            Ok(())
-        } else if self.appid.as_ref() != query.appid.as_ref() {
-            Err(Error::MultipleAppids)
-        } else {
-            // If the appids aren't different nor is self.appid == "NA" then
-            // the query's appid and self.appid are the same.
-            Ok(())
         }
     }
+
+    /// Persist this checkpoint crash-safely.
+    ///
+    /// Writes a temporary file, backs up any existing checkpoint to a sibling
+    /// `.bak`, then atomically renames the temp over the real file so a reader
+    /// never sees a half-written record. Call after each successfully written
+    /// page: a kill mid-run then resumes from the last committed cursor rather
+    /// than from scratch.
+    pub fn save<P>(&self, path: P) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let tmp = path.with_extension("checkpoint.tmp");
+        let json = serde_json::to_vec_pretty(self)
+            .map_err(|e| io::Error::new(ErrorKind::InvalidData, e))?;
+        fs::write(&tmp, json)?;
+
+        // Keep the previous checkpoint around in case the new one is ever found
+        // to be corrupt.
+        if path.exists() {
+            fs::copy(path, path.with_extension("checkpoint.bak"))?;
+        }
+        // Rename is atomic on the same filesystem.
+        fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    /// Load a checkpoint written by [`ResumeInfo::save`].
+    pub fn load<P>(path: P) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let raw = fs::read(path)?;
+        serde_json::from_slice(&raw)
+            .map_err(|e| Error::from(io::Error::new(ErrorKind::InvalidData, e)))
+    }
 }
 
 impl Default for ResumeInfo {
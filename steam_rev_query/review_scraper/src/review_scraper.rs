@@ -1,5 +1,6 @@
 use attohttpc::header::{COOKIE, USER_AGENT};
 use lazy_static::lazy_static;
+use rand::Rng;
 use rev_query_utils::error::{Error, Result};
 use scraper::{Html, Selector};
 use std::{
@@ -12,9 +13,223 @@ use steam_review_api::{
         flat_query::{FlattenedQuery, TitleSerde},
         SteamRevOuter,
     },
-    RevApiError, ReviewApi,
+    Language, RevApiError, ReviewApi,
 };
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
+
+// Retry budget for transient failures (HTTP 429/5xx, dropped connections). The
+// per-wait doubles from `BACKOFF_BASE`, is clamped to `BACKOFF_CAP`, and gets
+// ±50% jitter so a fleet of scrapers doesn't retry in lockstep.
+const MAX_RETRIES: u32 = 5;
+const BACKOFF_BASE: Duration = Duration::from_millis(500);
+const BACKOFF_CAP: Duration = Duration::from_secs(300);
+
+/// Why a page fetch failed: retryable (with an optional server-suggested wait)
+/// or a hard error that should surface immediately.
+///
+/// A [`Transport`] hands these back so [`ReviewScraper`]'s retry loop can decide
+/// whether to back off and try again.
+pub enum TransportError {
+    Transient {
+        retry_after: Option<Duration>,
+        reason: String,
+    },
+    Fatal(Error),
+}
+
+/// The HTTP fetch behind [`ReviewScraper`], abstracted so tests can replay saved
+/// pages instead of hammering live Steam.
+///
+/// Implementors take a fully built review-API `Url` and return the
+/// deserialized page, classifying failures as [`TransportError`]. The default is
+/// [`HttpTransport`]; tests use [`FixtureTransport`].
+pub trait Transport {
+    fn fetch(&self, url: &url::Url) -> std::result::Result<SteamRevOuter, TransportError>;
+
+    /// Scrape the game's title/appid. Defaults to the live store-page scrape;
+    /// offline transports override it so construction doesn't touch the network.
+    fn fetch_title(&self, appid: u32) -> (TitleSerde, TitleSerde) {
+        ReviewScraper::try_fetch_title(appid)
+    }
+}
+
+/// The live transport: a plain GET against Steam with the crate's user agent.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct HttpTransport;
+
+impl Transport for HttpTransport {
+    fn fetch(&self, url: &url::Url) -> std::result::Result<SteamRevOuter, TransportError> {
+        parse_page(&http_fetch_text(url)?)
+    }
+}
+
+// Issue one request and return the body text, mapping 429/5xx and dropped
+// connections to `TransportError::Transient` so the caller can back off.
+fn http_fetch_text(url: &url::Url) -> std::result::Result<String, TransportError> {
+    let response = attohttpc::get(url.clone())
+        .header("User-Agent", user_agent())
+        .send()
+        .map_err(classify_send_error)?;
+
+    let status = response.status().as_u16();
+    if status == 429 {
+        let retry_after = response
+            .headers()
+            .get(attohttpc::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(Duration::from_secs);
+        return Err(TransportError::Transient {
+            retry_after,
+            reason: "HTTP 429".to_owned(),
+        });
+    }
+    if (500..600).contains(&status) {
+        return Err(TransportError::Transient {
+            retry_after: None,
+            reason: format!("HTTP {}", status),
+        });
+    }
+
+    response
+        .text()
+        .map_err(|e| TransportError::Fatal(e.into()))
+}
+
+// Deserialize a page body. A body that won't parse is a hard error, not a
+// throttle.
+fn parse_page(raw: &str) -> std::result::Result<SteamRevOuter, TransportError> {
+    serde_json::from_str(raw).map_err(|e| {
+        TransportError::Fatal(Error::from(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            e,
+        )))
+    })
+}
+
+// A dropped/reset connection (an IO-level attohttpc error) is worth retrying; a
+// bad URL or malformed response is not.
+fn classify_send_error(error: attohttpc::Error) -> TransportError {
+    if matches!(error.kind(), attohttpc::ErrorKind::Io(_)) {
+        TransportError::Transient {
+            retry_after: None,
+            reason: error.to_string(),
+        }
+    } else {
+        TransportError::Fatal(error.into())
+    }
+}
+
+/// Whether a [`FixtureTransport`] replays saved pages or records fresh ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FixtureMode {
+    /// Read pages from disk; used by default in CI so tests stay offline.
+    Replay,
+    /// Fetch live pages and save them before returning, to seed fixtures once.
+    Record,
+}
+
+/// A [`Transport`] that serves review pages from on-disk fixtures keyed by
+/// appid and cursor (`<dir>/<appid>/<cursor>.json`).
+///
+/// In [`FixtureMode::Replay`] it reads the saved JSON, making the scraper
+/// deterministic and offline. In [`FixtureMode::Record`] it fetches the page
+/// live, writes it to the fixture path, then returns it, so the fixtures can be
+/// captured once and committed.
+#[derive(Debug, Clone)]
+pub struct FixtureTransport {
+    dir: std::path::PathBuf,
+    mode: FixtureMode,
+}
+
+impl FixtureTransport {
+    /// Replay fixtures from `dir`.
+    pub fn replay<P: Into<std::path::PathBuf>>(dir: P) -> Self {
+        Self {
+            dir: dir.into(),
+            mode: FixtureMode::Replay,
+        }
+    }
+
+    /// Record fixtures into `dir` from live responses.
+    pub fn record<P: Into<std::path::PathBuf>>(dir: P) -> Self {
+        Self {
+            dir: dir.into(),
+            mode: FixtureMode::Record,
+        }
+    }
+
+    // `<dir>/<appid>/<cursor>.json`, where the appid is the last URL path segment
+    // and the cursor is percent-escaped so it's always a valid file name.
+    fn path_for(&self, url: &url::Url) -> std::path::PathBuf {
+        let appid = url
+            .path_segments()
+            .and_then(|segments| segments.filter(|s| !s.is_empty()).last())
+            .unwrap_or("unknown")
+            .to_owned();
+        let cursor = url
+            .query_pairs()
+            .find(|(key, _)| key == "cursor")
+            .map(|(_, value)| value.into_owned())
+            .unwrap_or_else(|| "*".to_owned());
+        self.dir
+            .join(appid)
+            .join(format!("{}.json", fixture_key(&cursor)))
+    }
+}
+
+impl Transport for FixtureTransport {
+    // Don't scrape the live store page in replay mode; a null title is enough for
+    // offline tests, and record mode still grabs the real one.
+    fn fetch_title(&self, appid: u32) -> (TitleSerde, TitleSerde) {
+        match self.mode {
+            FixtureMode::Replay => (TitleSerde::default(), appid.to_string().into()),
+            FixtureMode::Record => ReviewScraper::try_fetch_title(appid),
+        }
+    }
+
+    fn fetch(&self, url: &url::Url) -> std::result::Result<SteamRevOuter, TransportError> {
+        let path = self.path_for(url);
+        match self.mode {
+            FixtureMode::Replay => {
+                let raw = std::fs::read_to_string(&path)
+                    .map_err(|e| TransportError::Fatal(e.into()))?;
+                parse_page(&raw)
+            }
+            FixtureMode::Record => {
+                let raw = http_fetch_text(url)?;
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)
+                        .map_err(|e| TransportError::Fatal(e.into()))?;
+                }
+                std::fs::write(&path, &raw).map_err(|e| TransportError::Fatal(e.into()))?;
+                parse_page(&raw)
+            }
+        }
+    }
+}
+
+// Percent-escape everything outside the file-name-safe set so a cursor like `*`
+// or `AoJw...==` maps to a stable, legal file name.
+fn fixture_key(cursor: &str) -> String {
+    let mut out = String::with_capacity(cursor.len());
+    for byte in cursor.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'.' | b'-' | b'_' => out.push(byte as char),
+            other => out.push_str(&format!("%{:02X}", other)),
+        }
+    }
+    out
+}
+
+// Exponential backoff off `BACKOFF_BASE`, capped at `BACKOFF_CAP`, with ±50%
+// jitter. `attempt` starts at 1.
+fn backoff_delay(attempt: u32) -> Duration {
+    let scaled = BACKOFF_BASE.saturating_mul(2u32.saturating_pow(attempt - 1));
+    let capped = scaled.min(BACKOFF_CAP);
+    let factor = rand::thread_rng().gen_range(0.5..1.5);
+    capped.mul_f64(factor)
+}
 
 // This only works with Cargo so I'll need an alternative.
 const fn user_agent() -> &'static str {
@@ -95,6 +310,18 @@ pub struct ReviewScraper {
     // mutually dependent due to one String was very painful.
     app_title: TitleSerde,
     appid: TitleSerde,
+    // Languages left to scrape for a cross-locale pass. Empty means single-language
+    // mode (whatever the ReviewApi was built with). The front of the queue is the
+    // language currently being paged.
+    languages: Vec<Language>,
+    // Steam's reported total for the current query and the cursor it handed back
+    // on the last page. Both are populated as pages arrive so a job scheduler can
+    // render progress/ETA without re-deriving them.
+    total_reviews: Option<u32>,
+    last_cursor: Option<String>,
+    // How pages are fetched. Defaults to live HTTP; tests swap in a fixture
+    // replayer so they don't depend on Steam.
+    transport: Box<dyn Transport>,
 }
 
 impl TryFrom<ReviewApi> for ReviewScraper {
@@ -113,14 +340,28 @@ impl TryFrom<ReviewApi> for ReviewScraper {
     /// ReviewScraper assumes that the caller wants pagination and thus
     /// returns `RevApiError::InvalidFilterCursor` for invalid states.
     fn try_from(query: ReviewApi) -> Result<Self> {
+        Self::with_transport(query, Box::new(HttpTransport))
+    }
+}
+
+impl ReviewScraper {
+    /// Build a scraper that fetches through a custom [`Transport`].
+    ///
+    /// The live [`TryFrom`] impls funnel through here with [`HttpTransport`];
+    /// tests pass a [`FixtureTransport`] to replay saved pages offline.
+    pub fn with_transport(query: ReviewApi, transport: Box<dyn Transport>) -> Result<Self> {
         if query.paging_ok() {
-            let (app_title, appid) = Self::try_fetch_title(query.current_appid());
+            let (app_title, appid) = transport.fetch_title(query.current_appid());
 
             Ok(Self {
                 query,
                 timer: DumbTimer::new(30),
                 app_title,
                 appid,
+                languages: Vec::new(),
+                total_reviews: None,
+                last_cursor: None,
+                transport,
             })
         } else {
             Err(RevApiError::InvalidFilterCursor.into())
@@ -149,15 +390,51 @@ impl TryFrom<&mut ReviewApi> for ReviewScraper {
 impl ReviewScraper {
     // Convenience function to build the internal query, send it, and receive
     // the response.
-    // Building the query and parsing the JSON shouldn't fail.
-    // Send might, though.
+    //
+    // Building the query (URL parse) shouldn't fail, and when it does it's not
+    // transient, so it bubbles straight up. The send/parse itself is wrapped in a
+    // retry loop: Steam throttles bulk scrapes with 429s and occasionally drops
+    // connections, both of which we back off and retry instead of aborting.
     fn send_request(&mut self) -> Result<SteamRevOuter> {
-        // Unfortunately, this will wait for the first request as well!
-        self.timer.wait_fire();
-        Ok(attohttpc::get(self.query.build()?)
-            .header("User-Agent", user_agent())
-            .send()?
-            .json::<SteamRevOuter>()?)
+        let url = self.query.build()?;
+
+        let mut attempt = 0;
+        let mut last_delay = Duration::ZERO;
+        loop {
+            // Unfortunately, this will wait for the first request as well!
+            self.timer.wait_fire();
+
+            match self.transport.fetch(&url) {
+                Ok(outer) => return Ok(outer),
+                // CSV/URL/JSON parse problems aren't worth retrying.
+                Err(TransportError::Fatal(e)) => return Err(e),
+                Err(TransportError::Transient {
+                    retry_after,
+                    reason,
+                }) => {
+                    attempt += 1;
+                    if attempt > MAX_RETRIES {
+                        return Err(Error::RateLimited {
+                            attempts: MAX_RETRIES,
+                            last: last_delay,
+                        });
+                    }
+                    // Honour Steam's Retry-After when it sends one; otherwise fall
+                    // back to jittered exponential backoff.
+                    let delay = retry_after.unwrap_or_else(|| backoff_delay(attempt));
+                    last_delay = delay;
+                    warn!(
+                        "Transient failure pulling {} ({}); retry {}/{} in {:?}.",
+                        self.appid.as_ref(),
+                        reason,
+                        attempt,
+                        MAX_RETRIES,
+                        delay
+                    );
+                    std::thread::sleep(delay);
+                }
+            }
+        }
     }
 
     pub fn pull<B>(&mut self) -> Result<B>
@@ -170,6 +447,9 @@ impl ReviewScraper {
             self.appid.as_ref(),
             self.app_title.as_ref()
         );
+        // Stash the progress bits before the cursor is moved into the query.
+        self.total_reviews = raw_query.query_summary.total_reviews;
+        self.last_cursor = Some(raw_query.cursor.clone());
         // Update cursor for pagination.
         // This shouldn't fail because we checked if pagination is okay when we built the Scraper.
         // (And either way using day_range is messy).
@@ -252,6 +532,63 @@ impl ReviewScraper {
     pub fn title(&self) -> &str {
         self.app_title.as_ref()
     }
+
+    /// The appid this scraper is pulling, as a string.
+    #[inline]
+    pub fn appid(&self) -> &str {
+        self.appid.as_ref()
+    }
+
+    /// Steam's reported total review count for the current query, once at least
+    /// one page has come back. `None` before the first `pull`.
+    #[inline]
+    pub fn total_reviews(&self) -> Option<u32> {
+        self.total_reviews
+    }
+
+    /// The cursor Steam returned on the most recent page, for progress display or
+    /// checkpointing. `None` before the first `pull`.
+    #[inline]
+    pub fn last_cursor(&self) -> Option<&str> {
+        self.last_cursor.as_deref()
+    }
+
+    /// Build a scraper that pulls reviews across a set of languages in one pass.
+    ///
+    /// The scraper pages the first language to exhaustion, then rotates the
+    /// `ReviewApi`'s `language` param to the next, resets the cursor, and keeps
+    /// going — reusing the already-scraped `app_title`/`appid` and respecting the
+    /// same `DumbTimer` rate limiting. Each emitted `FlattenedQuery` is tagged with
+    /// the language it came back in (the per-review `language` field).
+    ///
+    /// An empty `languages` slice behaves like [`ReviewScraper::try_from`].
+    pub fn with_languages(query: ReviewApi, languages: &[Language]) -> Result<Self> {
+        let mut scraper: Self = query.try_into()?;
+        // Seed the first language up front so the opening page is already in it.
+        let mut languages = languages.to_vec();
+        if !languages.is_empty() {
+            scraper.query.language(languages[0].clone());
+            // Drop the head; it's now the active language.
+            languages.remove(0);
+            scraper.languages = languages;
+        }
+        Ok(scraper)
+    }
+
+    // Rotate to the next queued language, resetting the cursor so paging starts
+    // from the top for it. Returns false once every language has been scraped.
+    fn advance_language(&mut self) -> bool {
+        if self.languages.is_empty() {
+            return false;
+        }
+        let next = self.languages.remove(0);
+        self.query.language(next);
+        // A fresh language is a fresh pagination run.
+        self.query
+            .change_cursor("*".to_owned(), true)
+            .expect("Pagination was already validated when the scraper was built.");
+        true
+    }
 }
 
 impl Iterator for ReviewScraper {
@@ -266,18 +603,23 @@ impl Iterator for ReviewScraper {
         // Without doing this the Iterator could produce
         // sequences of Some with empty Vectors which is dumb
         // and may cause problems too.
-        match self.pull() {
-            Result::<Vec<FlattenedQuery>>::Ok(query) => {
-                if !query.is_empty() {
-                    Some(Ok(query))
-                } else {
-                    None
+        loop {
+            match self.pull() {
+                Result::<Vec<FlattenedQuery>>::Ok(query) => {
+                    if !query.is_empty() {
+                        return Some(Ok(query));
+                    }
+                    // This language is exhausted. In multi-language mode, roll to
+                    // the next one and keep pulling; otherwise we're done.
+                    if !self.advance_language() {
+                        return None;
+                    }
                 }
+                // Don't wanna discard errors even if this looks clumsy.
+                // Transposing Err => None doesn't solve the problem above
+                // but also discards errors. Losses all around.
+                Err(e) => return Some(Err(e)),
             }
-            // Don't wanna discard errors even if this looks clumsy.
-            // Transposing Err => None doesn't solve the problem above
-            // but also discards errors. Losses all around.
-            Err(e) => Some(Err(e)),
         }
     }
 }
@@ -292,7 +634,9 @@ mod tests {
     const SYMPHONIA: u32 = 372360;
     const SYMPHONIA_TITLE: &'static str = "Tales of Symphonia";
 
+    // Hits the live store page, so it's opt-in: `cargo test -- --ignored`.
     #[test]
+    #[ignore = "hits live Steam"]
     fn can_i_haz_title() {
         let (title_doom, _appid_doom) = ReviewScraper::try_fetch_title(DOOM_2016);
         assert_eq!(title_doom.as_ref(), DOOM_2016_TITLE);
@@ -5,14 +5,34 @@ use rev_query_utils::{
     resumeinfo::ResumeInfo,
 };
 use std::{
-    collections::hash_map::DefaultHasher,
+    collections::{hash_map::DefaultHasher, HashMap},
     fs::File,
     hash::{Hash, Hasher},
+    io::{Read, Write},
     iter::FromIterator,
     path::Path,
+    time::Instant,
 };
 use steam_review_api::convenience_structs::flat_query::FlattenedQuery;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
+
+#[cfg(feature = "encrypt")]
+mod crypto;
+#[cfg(feature = "encrypt")]
+pub use crypto::EncryptionKey;
+
+#[cfg(feature = "compress")]
+mod codec;
+#[cfg(feature = "compress")]
+pub use codec::Codec;
+
+// The CSV writer no longer sits directly on a `File`. Boxing the sink lets an
+// optional streaming-cipher (or, later, compression) layer slot in underneath
+// `csv::Writer` without the cache code caring what's actually on disk.
+type CacheSink = Box<dyn Write + Send>;
+// Mirror of `CacheSink` for the resume path, where we read a previous scrape
+// back through whatever layer wrote it.
+type CacheSource = Box<dyn Read>;
 
 #[derive(Debug)]
 pub struct ResumeScraperCache {
@@ -20,6 +40,16 @@ pub struct ResumeScraperCache {
     pub resume_info: ResumeInfo,
 }
 
+/// A resume split across appids: one shared [`ScraperCache`] over the
+/// consolidated CSV plus a per-appid [`ResumeInfo`], keyed by appid string, so
+/// each game's cursor advances independently when the scrapers are fanned out
+/// to the job scheduler.
+#[derive(Debug)]
+pub struct PartitionedResume {
+    pub cache: ScraperCache,
+    pub resume_info: HashMap<String, ResumeInfo>,
+}
+
 #[derive(Debug)]
 pub struct ScraperCache {
     // Hashes of FlattenedQuery.
@@ -29,8 +59,8 @@ pub struct ScraperCache {
     cache: Vec<FlattenedQuery>,
     // Current index of unwritten data
     write_index: usize,
-    // CSV file.
-    file: Writer<File>,
+    // CSV file, possibly layered over a streaming cipher.
+    file: Writer<CacheSink>,
 }
 
 impl ScraperCache {
@@ -40,10 +70,46 @@ impl ScraperCache {
     {
         // Write to a new file rather than resuming a scrape.
         let csv_file = File::with_options()
+            .create_new(true)
+            .write(true)
+            .open(&path)?;
+        // A `.csv.gz`/`.csv.zst` output is compressed transparently; anything
+        // else lands as plaintext.
+        #[cfg(feature = "compress")]
+        let sink = codec::wrap_writer(codec::Codec::from_path(&path), csv_file)?;
+        #[cfg(not(feature = "compress"))]
+        let sink = Box::new(csv_file) as CacheSink;
+        let csv_writer = Writer::from_writer(sink);
+
+        Ok(Self {
+            seen_set: HashedSet::with_capacity_and_hasher(cache_size, HashBuildHasher::default()),
+            cache: Vec::with_capacity(cache_size),
+            write_index: 0,
+            file: csv_writer,
+        })
+    }
+
+    /// Like [`ScraperCache::new`] but encrypts the output at rest with a
+    /// ChaCha20 keystream derived from `key`.
+    ///
+    /// A small plaintext header (salt + nonce) is written first so the same
+    /// passphrase can rederive the key and decrypt — and resume — the file
+    /// later via [`ScraperCache::resume_from_file_encrypted`]. Everything after
+    /// the header, including the CSV header row, is ciphertext.
+    #[cfg(feature = "encrypt")]
+    pub fn new_encrypted<P>(cache_size: usize, path: P, key: &EncryptionKey) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let mut csv_file = File::with_options()
             .create_new(true)
             .write(true)
             .open(path)?;
-        let csv_writer = Writer::from_writer(csv_file);
+        // Fresh file: write the header, then start the cipher at keystream offset
+        // zero (right after the header).
+        let header = crypto::write_header(&mut csv_file)?;
+        let sink = crypto::encrypting_writer(csv_file, key, &header, 0);
+        let csv_writer = Writer::from_writer(Box::new(sink) as CacheSink);
 
         Ok(Self {
             seen_set: HashedSet::with_capacity_and_hasher(cache_size, HashBuildHasher::default()),
@@ -53,9 +119,11 @@ impl ScraperCache {
         })
     }
 
-    /// Resume a scrape from a CSV file.
-    /// Scrapes are only resumeable from a single appid. The file specified by `path` shouldn't contain
-    /// multiple appids. Timestamps are required.
+    /// Resume a scrape from a CSV file holding a single appid.
+    /// For heterogeneous files prefer [`ScraperCache::resume_from_file_partitioned`],
+    /// which keeps a cursor per appid; this entry point collapses to the first
+    /// appid and warns if the file turns out to span several.
+    /// Timestamps are required.
     #[tracing::instrument]
     pub fn resume_from_file<P>(
         cache_size: usize,
@@ -63,24 +131,75 @@ impl ScraperCache {
         // Fail on errors while parsing if true else skip the row.
         fail_on_error: bool,
     ) -> Result<ResumeScraperCache>
+    where
+        P: AsRef<Path> + std::fmt::Debug + std::fmt::Display,
+    {
+        // Reuse the partitioned reader and collapse to a single appid, keeping the
+        // historical "one file, one game" contract for this entry point.
+        let PartitionedResume { cache, resume_info } =
+            Self::resume_from_file_partitioned(cache_size, path, fail_on_error)?;
+        if resume_info.len() > 1 {
+            warn!(
+                "File spans {} appids; resuming only the first in sorted order. Use \
+                 the multi-appid path to resume them concurrently.",
+                resume_info.len()
+            );
+        }
+        Ok(ResumeScraperCache {
+            cache,
+            // Pick the first appid in sorted order rather than an arbitrary HashMap
+            // entry so the collapse is deterministic across runs. An empty file
+            // resumes from a default (null appid) as before.
+            resume_info: resume_info
+                .into_iter()
+                .min_by(|(left, _), (right, _)| left.cmp(right))
+                .map(|(_, info)| info)
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Resume a scrape from a file that may span multiple appids.
+    ///
+    /// Unlike [`ScraperCache::resume_from_file`], heterogeneous files aren't
+    /// rejected: rows are grouped by appid while the shared `seen_set` is built,
+    /// producing a [`ResumeInfo`] per appid (each tracking that appid's oldest
+    /// timestamp for its own `day_range`). The single append writer over the
+    /// consolidated CSV is shared so every fanned-out scraper keeps writing to
+    /// one file.
+    #[tracing::instrument]
+    pub fn resume_from_file_partitioned<P>(
+        cache_size: usize,
+        path: P,
+        fail_on_error: bool,
+    ) -> Result<PartitionedResume>
     where
         P: AsRef<Path> + std::fmt::Debug + std::fmt::Display,
     {
         let mut seen_set =
             HashedSet::with_capacity_and_hasher(cache_size, HashBuildHasher::default());
-        let mut resume_info = ResumeInfo::default();
+        let mut resume_info: HashMap<String, ResumeInfo> = HashMap::new();
 
         {
-            let mut csv_reader = Reader::from_path(&path)?;
+            // Read the previous scrape back through whatever codec wrote it.
+            #[cfg(feature = "compress")]
+            let source = codec::wrap_reader(codec::Codec::from_path(&path), File::open(&path)?)?;
+            #[cfg(not(feature = "compress"))]
+            let source = Box::new(File::open(&path)?) as CacheSource;
+            let mut csv_reader = Reader::from_reader(source);
+
             for flat_query in csv_reader.deserialize::<FlattenedQuery>() {
                 match flat_query {
                     Ok(flat_query) => {
                         let mut hasher = DefaultHasher::new();
                         flat_query.hash(&mut hasher);
-                        let hash = hasher.finish();
-                        seen_set.insert(hash);
+                        seen_set.insert(hasher.finish());
 
-                        resume_info.update(&flat_query)?;
+                        // Each appid gets its own cursor; the keyed map keeps the
+                        // per-appid `ResumeInfo` independent.
+                        resume_info
+                            .entry(flat_query.appid.as_ref().to_owned())
+                            .or_default()
+                            .update(&flat_query);
                     }
                     Err(e) if fail_on_error => return Err(e.into()),
                     Err(e) => {
@@ -93,9 +212,53 @@ impl ScraperCache {
             }
         }
 
-        // Append to a scrape
-        let csv_file = File::with_options().append(true).open(path)?;
-        let csv_writer = Writer::from_writer(csv_file);
+        // One append writer shared across all appids' scrapers.
+        let csv_file = File::with_options().append(true).open(&path)?;
+        #[cfg(feature = "compress")]
+        let sink = codec::wrap_writer(codec::Codec::from_path(&path), csv_file)?;
+        #[cfg(not(feature = "compress"))]
+        let sink = Box::new(csv_file) as CacheSink;
+        let csv_writer = Writer::from_writer(sink);
+
+        Ok(PartitionedResume {
+            cache: ScraperCache {
+                seen_set,
+                cache: Vec::with_capacity(cache_size),
+                write_index: 0,
+                file: csv_writer,
+            },
+            resume_info,
+        })
+    }
+
+    /// Resume a scrape from a file written by [`ScraperCache::new_encrypted`].
+    ///
+    /// Reads the header, rederives the key from `key`'s passphrase, decrypts the
+    /// existing rows to rebuild `seen_set`/`ResumeInfo`, and then reopens the file
+    /// for appending with the cipher seeked to the end of the current ciphertext
+    /// so new rows continue the same keystream.
+    #[cfg(feature = "encrypt")]
+    #[tracing::instrument(skip(key))]
+    pub fn resume_from_file_encrypted<P>(
+        cache_size: usize,
+        path: P,
+        fail_on_error: bool,
+        key: &EncryptionKey,
+    ) -> Result<ResumeScraperCache>
+    where
+        P: AsRef<Path> + std::fmt::Debug + std::fmt::Display,
+    {
+        let header = crypto::read_header(&path)?;
+        let decryptor = crypto::decrypting_reader(File::open(&path)?, key, &header)?;
+        let reader = Reader::from_reader(Box::new(decryptor) as CacheSource);
+        let (seen_set, resume_info) = Self::rebuild_seen_set(reader, cache_size, fail_on_error, &path)?;
+
+        // Pick up the keystream where the existing ciphertext ends so appended
+        // rows decrypt cleanly alongside the old ones.
+        let csv_file = File::with_options().append(true).open(&path)?;
+        let offset = crypto::ciphertext_len(&path)?;
+        let sink = crypto::encrypting_writer(csv_file, key, &header, offset);
+        let csv_writer = Writer::from_writer(Box::new(sink) as CacheSink);
 
         Ok(ResumeScraperCache {
             cache: ScraperCache {
@@ -108,8 +271,54 @@ impl ScraperCache {
         })
     }
 
+    // Walk a CSV reader, hashing each row into `seen_set` and folding the oldest
+    // timestamp/appid into a single `ResumeInfo`. Used by the encrypted resume
+    // path; the plaintext path builds a per-appid map in
+    // `resume_from_file_partitioned` instead.
+    fn rebuild_seen_set<R, P>(
+        mut csv_reader: Reader<R>,
+        cache_size: usize,
+        fail_on_error: bool,
+        path: &P,
+    ) -> Result<(HashedSet<u64>, ResumeInfo)>
+    where
+        R: Read,
+        P: std::fmt::Display + ?Sized,
+    {
+        let mut seen_set =
+            HashedSet::with_capacity_and_hasher(cache_size, HashBuildHasher::default());
+        let mut resume_info = ResumeInfo::default();
+
+        for flat_query in csv_reader.deserialize::<FlattenedQuery>() {
+            match flat_query {
+                Ok(flat_query) => {
+                    let mut hasher = DefaultHasher::new();
+                    flat_query.hash(&mut hasher);
+                    let hash = hasher.finish();
+                    seen_set.insert(hash);
+
+                    resume_info.update(&flat_query);
+                }
+                Err(e) if fail_on_error => return Err(e.into()),
+                Err(e) => {
+                    error!(
+                        "WARNING: Failed to parse a row of the CSV: {}.\nError given: {}",
+                        path, e
+                    )
+                }
+            }
+        }
+
+        Ok((seen_set, resume_info))
+    }
+
     /// Write the entire cache out to file or resume a failed write.
     pub fn flush_cache(&mut self) -> Result<()> {
+        // Time the serialize loop so `--debug` can show whether disk I/O is the
+        // bottleneck on a slow scrape.
+        let start = Instant::now();
+        let rows = self.cache.len().saturating_sub(self.write_index);
+
         // I'm not draining the cache in order to handle errors if necessary.
         // Draining would clear the cache once the iterator is dropped.
         for (i, query) in self.cache.iter().enumerate().skip(self.write_index) {
@@ -123,6 +332,14 @@ impl ScraperCache {
             }
         }
 
+        let elapsed = start.elapsed();
+        debug!(
+            rows,
+            flush_ms = elapsed.as_secs_f64() * 1000.0,
+            rows_per_sec = rows_per_sec(rows, elapsed),
+            "Flushed cache to disk."
+        );
+
         self.cache.clear();
         self.write_index = 0;
         Ok(())
@@ -190,8 +407,24 @@ impl ScraperCache {
 
     #[tracing::instrument]
     pub fn insert(&mut self, data: &[FlattenedQuery]) -> Result<()> {
+        // Time the hashing/dedup pass and record how much of the batch was
+        // duplicate so `--debug` can separate hashing cost from network/disk.
+        let start = Instant::now();
         let filtered_data: Vec<_> = self.filter_data(data);
+        let hash_elapsed = start.elapsed();
+
         let length = filtered_data.len();
+        let duplicates = data.len().saturating_sub(length);
+        debug!(
+            batch = data.len(),
+            unique = length,
+            duplicates,
+            dup_rate = dup_rate(duplicates, data.len()),
+            hash_ms = hash_elapsed.as_secs_f64() * 1000.0,
+            rows_per_sec = rows_per_sec(data.len(), hash_elapsed),
+            "Hashed and deduplicated a batch."
+        );
+
         if length > 0 {
             info!("{} valid, unique nodes scraped.", length);
             self.process_data(&filtered_data)
@@ -202,6 +435,26 @@ impl ScraperCache {
     }
 }
 
+// Rows processed per second over `elapsed`, reported as a tracing field. Returns
+// zero for a zero-length interval rather than dividing by zero.
+fn rows_per_sec(rows: usize, elapsed: std::time::Duration) -> f64 {
+    let secs = elapsed.as_secs_f64();
+    if secs > 0.0 {
+        rows as f64 / secs
+    } else {
+        0.0
+    }
+}
+
+// Fraction of a batch that was filtered out as already-seen, in [0, 1].
+fn dup_rate(duplicates: usize, batch: usize) -> f64 {
+    if batch > 0 {
+        duplicates as f64 / batch as f64
+    } else {
+        0.0
+    }
+}
+
 // Ensure that the cached data are written out when the cache is dropped.
 impl Drop for ScraperCache {
     #[tracing::instrument]
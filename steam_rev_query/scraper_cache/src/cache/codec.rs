@@ -0,0 +1,61 @@
+//! Transparent compression for the scraper's on-disk CSV.
+//!
+//! A codec is interposed between the `File` and `csv::Writer` so long scrapes of
+//! popular appids don't balloon into multi-gigabyte plaintext. The codec is
+//! inferred from the output extension (`.csv.gz`, `.csv.zst`); anything else is
+//! left uncompressed.
+//!
+//! Resume appends a fresh gzip member / zstd frame to the existing file rather
+//! than rewriting it, so the matching decoder must read concatenated streams —
+//! [`flate2::read::MultiGzDecoder`] and `zstd`'s multi-frame decoder both do.
+
+use flate2::{read::MultiGzDecoder, write::GzEncoder, Compression};
+use rev_query_utils::error::Result;
+use std::{fs::File, path::Path};
+
+use super::{CacheSink, CacheSource};
+
+/// On-disk codec for the scraped CSV, selected by file extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Codec {
+    /// Plaintext `.csv`.
+    None,
+    /// gzip (`.csv.gz`).
+    Gzip,
+    /// zstd (`.csv.zst`).
+    Zstd,
+}
+
+impl Codec {
+    /// Infer the codec from a path's extension, defaulting to [`Codec::None`].
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Self {
+        match path.as_ref().extension().and_then(|ext| ext.to_str()) {
+            Some("gz") => Codec::Gzip,
+            Some("zst") | Some("zstd") => Codec::Zstd,
+            _ => Codec::None,
+        }
+    }
+}
+
+/// Wrap `file` in the encoder for `codec`, returning a boxed sink.
+///
+/// Used for both fresh writes and resume appends: a `GzEncoder` over an
+/// append handle emits a new gzip member, and a zstd encoder a new frame.
+pub fn wrap_writer(codec: Codec, file: File) -> Result<CacheSink> {
+    Ok(match codec {
+        Codec::None => Box::new(file),
+        Codec::Gzip => Box::new(GzEncoder::new(file, Compression::default())),
+        Codec::Zstd => Box::new(zstd::Encoder::new(file, 0)?.auto_finish()),
+    })
+}
+
+/// Wrap `file` in the decoder for `codec`, returning a boxed source.
+pub fn wrap_reader(codec: Codec, file: File) -> Result<CacheSource> {
+    Ok(match codec {
+        Codec::None => Box::new(file) as CacheSource,
+        Codec::Gzip => Box::new(MultiGzDecoder::new(file)),
+        // `Decoder::new` reads every frame in a multi-frame file, which is what
+        // the append-a-frame resume path produces.
+        Codec::Zstd => Box::new(zstd::Decoder::new(file)?),
+    })
+}
@@ -0,0 +1,179 @@
+//! Streaming ChaCha20 encryption for the scraper's on-disk CSV.
+//!
+//! The layout is a small plaintext header followed by a single ChaCha20
+//! keystream covering every byte the CSV writer emits:
+//!
+//! ```text
+//! | MAGIC (8) | salt (16) | nonce (12) | ciphertext... |
+//! ```
+//!
+//! The salt is the only KDF parameter we vary per file; the passphrase is
+//! stretched into a 32-byte key with SHA-256 over `salt || passphrase`. Because
+//! ChaCha20 is seekable by byte offset, a resume can reopen the file, read the
+//! header, and advance the keystream to the end of the existing ciphertext
+//! before appending — see [`encrypting_writer`]'s `offset` argument.
+
+use chacha20::{
+    cipher::{KeyIvInit, StreamCipher, StreamCipherSeek},
+    ChaCha20,
+};
+use rand::RngCore;
+use rev_query_utils::error::{Error, Result};
+use sha2::{Digest, Sha256};
+use std::{
+    fs::File,
+    io::{self, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+// Identifies the on-disk format so a plaintext CSV isn't mistaken for an
+// encrypted one (and vice versa).
+const MAGIC: &[u8; 8] = b"SRQENC1\n";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const HEADER_LEN: u64 = (MAGIC.len() + SALT_LEN + NONCE_LEN) as u64;
+
+/// A passphrase used to encrypt or resume an encrypted scrape.
+///
+/// The passphrase itself is held until a file's salt is known; the 32-byte key
+/// is derived per file so two scrapes never share a keystream.
+#[derive(Clone)]
+pub struct EncryptionKey {
+    passphrase: Vec<u8>,
+}
+
+impl EncryptionKey {
+    /// Build a key from a passphrase (typically a CLI flag or an env var).
+    #[inline]
+    pub fn from_passphrase<S: AsRef<str>>(passphrase: S) -> Self {
+        Self {
+            passphrase: passphrase.as_ref().as_bytes().to_vec(),
+        }
+    }
+
+    // Stretch the passphrase into a ChaCha20 key using the file's salt.
+    fn derive(&self, salt: &[u8; SALT_LEN]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(salt);
+        hasher.update(&self.passphrase);
+        hasher.finalize().into()
+    }
+}
+
+// Deliberately opaque so a passphrase can't leak into a log line.
+impl std::fmt::Debug for EncryptionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("EncryptionKey(<redacted>)")
+    }
+}
+
+/// A `Write` that enciphers bytes with a ChaCha20 keystream before handing them
+/// to `inner`.
+pub struct ChaCha20Writer<W> {
+    inner: W,
+    cipher: ChaCha20,
+}
+
+impl<W: Write> Write for ChaCha20Writer<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // Encipher into a scratch buffer so the caller's slice is left intact.
+        let mut scratch = buf.to_vec();
+        self.cipher.apply_keystream(&mut scratch);
+        self.inner.write_all(&scratch)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// A `Read` that deciphers bytes pulled from `inner`.
+pub struct ChaCha20Reader<R> {
+    inner: R,
+    cipher: ChaCha20,
+}
+
+impl<R: Read> Read for ChaCha20Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.cipher.apply_keystream(&mut buf[..read]);
+        Ok(read)
+    }
+}
+
+/// A parsed on-disk header: the per-file KDF salt and the keystream nonce.
+pub struct Header {
+    salt: [u8; SALT_LEN],
+    nonce: [u8; NONCE_LEN],
+}
+
+/// Mint a fresh header and write it to the front of `file`.
+///
+/// Called once for a new scrape, before any ciphertext.
+pub fn write_header(file: &mut File) -> Result<Header> {
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    let mut rng = rand::thread_rng();
+    rng.fill_bytes(&mut salt);
+    rng.fill_bytes(&mut nonce);
+
+    file.write_all(MAGIC)?;
+    file.write_all(&salt)?;
+    file.write_all(&nonce)?;
+    Ok(Header { salt, nonce })
+}
+
+/// Read and validate the header sitting at the front of `path`.
+pub fn read_header<P: AsRef<Path>>(path: P) -> Result<Header> {
+    let mut file = File::open(path)?;
+    let mut magic = [0u8; 8];
+    file.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(Error::Io(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "File is not an encrypted scrape (bad magic).",
+        )));
+    }
+
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    file.read_exact(&mut salt)?;
+    file.read_exact(&mut nonce)?;
+    Ok(Header { salt, nonce })
+}
+
+/// Build an encrypting writer over `file` whose keystream starts at `offset`
+/// bytes into the ciphertext.
+///
+/// `offset` is zero for a fresh scrape and the length of the existing ciphertext
+/// for a resume so appended rows continue the same keystream.
+pub fn encrypting_writer(
+    file: File,
+    key: &EncryptionKey,
+    header: &Header,
+    offset: u64,
+) -> ChaCha20Writer<File> {
+    let mut cipher = ChaCha20::new(&key.derive(&header.salt).into(), &header.nonce.into());
+    cipher.seek(offset);
+    ChaCha20Writer { inner: file, cipher }
+}
+
+/// Build a decrypting reader over `file`, seeking it past the header so reads
+/// start at the first ciphertext byte.
+pub fn decrypting_reader(
+    mut file: File,
+    key: &EncryptionKey,
+    header: &Header,
+) -> Result<ChaCha20Reader<File>> {
+    file.seek(SeekFrom::Start(HEADER_LEN))?;
+    let cipher = ChaCha20::new(&key.derive(&header.salt).into(), &header.nonce.into());
+    Ok(ChaCha20Reader { inner: file, cipher })
+}
+
+/// Length of the ciphertext portion of `path` (file length minus the header),
+/// used to seek the keystream when appending on resume.
+pub fn ciphertext_len<P: AsRef<Path>>(path: P) -> Result<u64> {
+    let len = std::fs::metadata(path)?.len();
+    Ok(len.saturating_sub(HEADER_LEN))
+}
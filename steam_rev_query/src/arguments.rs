@@ -3,7 +3,9 @@ use clap::{App, Arg, ArgMatches};
 use either::Either;
 use rev_query_utils::error::{Error, Result};
 use review_scraper::ReviewScraper;
-use scraper_cache::{ResumeScraperCache, ScraperCache};
+use scraper_cache::{PartitionedResume, ScraperCache};
+#[cfg(feature = "encrypt")]
+use scraper_cache::ResumeScraperCache;
 use std::{
     convert::TryInto,
     io::ErrorKind,
@@ -12,11 +14,29 @@ use std::{
 use steam_review_api::{
     convenience_structs::flat_query::FlattenedQuery, Filter, ReviewApi, ReviewType,
 };
-use tracing::{info, warn};
+use tracing::{error, info, warn};
 
-const DEFAULT_CACHE_SIZE: usize = 500;
+use crate::{config::Config, jobs};
 trait EndAfterZero = Fn(Result<Vec<FlattenedQuery>>) -> Result<Vec<FlattenedQuery>>;
 
+// Resolve an optional encryption passphrase from the `--encrypt` flag, falling
+// back to $STEAM_SCRAPER_KEY when the flag is given without a value. Returns
+// `None` when encryption wasn't requested at all.
+#[cfg(feature = "encrypt")]
+fn encryption_key(matches: &ArgMatches<'static>) -> Option<scraper_cache::EncryptionKey> {
+    if !matches.is_present("encrypt") {
+        return None;
+    }
+    let passphrase = matches
+        .value_of("encrypt")
+        .map(str::to_owned)
+        .or_else(|| std::env::var("STEAM_SCRAPER_KEY").ok())
+        .unwrap_or_else(|| {
+            panic!("--encrypt was given without a passphrase and $STEAM_SCRAPER_KEY is unset.")
+        });
+    Some(scraper_cache::EncryptionKey::from_passphrase(passphrase))
+}
+
 fn end_after_zero_wrap(
     item: Result<Vec<FlattenedQuery>>,
     keep_going: bool,
@@ -30,6 +50,47 @@ fn end_after_zero_wrap(
     }
 }
 
+// Append the codec's extension to `path` when `--compress` is set and the path
+// doesn't already end in it, so the cache's extension-based inference selects
+// the right encoder.
+fn compressed_output(path: &str, matches: &ArgMatches<'static>) -> String {
+    match matches.value_of("compress") {
+        Some("gzip") if !path.ends_with(".gz") => format!("{}.gz", path),
+        Some("zstd") if !path.ends_with(".zst") && !path.ends_with(".zstd") => {
+            format!("{}.zst", path)
+        }
+        _ => path.to_owned(),
+    }
+}
+
+// Turn one appid's oldest-seen timestamp into a resumable `ReviewApi`. Steam's
+// API has no server-side cursor persistence, so we replay from a `day_range`
+// wide enough to cover everything written since the last scraped review.
+fn resume_api(appid: u32, earliest_secs: i64, review_type: ReviewType) -> ReviewApi {
+    let last_scraped_time = Utc.timestamp(earliest_secs, 0);
+    let current_time = offset::Utc::now();
+    let days_ago = (current_time - last_scraped_time).num_days();
+
+    if days_ago.is_negative() {
+        panic!(
+            r#"The earliest timestamp in the provided file is more recent than today; check the provided file again.
+               Last scraped time: {}
+               Current time UTC: {}
+               Elapsed days: {}"#,
+            last_scraped_time, current_time, days_ago
+        );
+    }
+
+    let mut review_api = ReviewApi::new(appid);
+    review_api.filter(Filter::All)
+        .expect("Failed to change the Filter to Filter::All for resuming a scrape. This shouldn't happen.")
+        .day_range(days_ago.try_into().expect(&format!("days_ago can't fit into a u32 for some reason: {}", days_ago)))
+        .expect("Failed to set day_range while resuming a scrape; this is surely a bug.")
+        .num_per_page(100)
+        .review_type(review_type);
+    review_api
+}
+
 pub(crate) struct ScraperAppSettings<IterMapFn>
 where
     IterMapFn: EndAfterZero,
@@ -86,7 +147,7 @@ fn build_arguments() -> ArgMatches<'static> {
         .arg(
             Arg::with_name("OUTPUT")
             .help("Write scrape results to or resume from this file.")
-            .required(true)
+            .required_unless("batch")
             .index(1)
         )
         .arg(
@@ -95,7 +156,7 @@ fn build_arguments() -> ArgMatches<'static> {
                 .long("appid")
                 .help("Steam appid to scrape. Find the appid via the Steam Store.")
                 .takes_value(true)
-                .required_unless("resume"),
+                .required_unless_one(&["resume", "batch"]),
         )
         .arg(
             Arg::with_name("review_type")
@@ -110,7 +171,14 @@ fn build_arguments() -> ArgMatches<'static> {
             .long("resume")
             .help("Resume a scrape rather than starting a new one. A file containing the previous scrape must be provided.")
             .takes_value(false)
-            .required_unless("appid")
+            .required_unless_one(&["appid", "batch"])
+        )
+        .arg(
+            Arg::with_name("batch")
+            .short("b")
+            .long("batch")
+            .help("Scrape the declarative [[batch]] list from the config file concurrently, each appid to its own output path. Ignores OUTPUT/--appid.")
+            .takes_value(false)
         )
         .arg(
             Arg::with_name("end_after_zero")
@@ -138,33 +206,131 @@ fn build_arguments() -> ArgMatches<'static> {
               .help("Set a cache size in number of items. Trade off is more disk writes (lower) versus more memory use (higher). Defaults to 500.")
               .takes_value(true)
         )
+        .arg(Arg::with_name("debug")
+              .short("d")
+              .long("debug")
+              .help("Raise logging to DEBUG, emitting per-batch timing and throughput for the cache pipeline.")
+              .takes_value(false)
+        )
+        .arg(Arg::with_name("compress")
+              .short("z")
+              .long("compress")
+              .help("Compress the output transparently: 'gzip' or 'zstd'. May also be inferred from a .csv.gz / .csv.zst extension.")
+              .takes_value(true)
+              .possible_values(&["gzip", "zstd"])
+        )
+        .arg(Arg::with_name("encrypt")
+              .short("k")
+              .long("encrypt")
+              .help("Encrypt the output at rest with a passphrase (or $STEAM_SCRAPER_KEY if the value is omitted). Resuming an encrypted file needs the same passphrase.")
+              .takes_value(true)
+              .min_values(0)
+        )
         .get_matches()
 }
 
+// Fan the config's declarative batch into the job scheduler and log each event as
+// it arrives, blocking until every appid's job finishes.
+fn run_batch(config: &Config) {
+    if config.batch.is_empty() {
+        warn!("--batch was given but the config has no [[batch]] entries; nothing to do.");
+        return;
+    }
+
+    let review_type = config.review_type();
+    let specs: Vec<jobs::JobSpec> = config
+        .batch
+        .iter()
+        .map(|entry| jobs::JobSpec {
+            appid: entry.appid,
+            output: entry.output.clone(),
+            review_type,
+            cache_size: config.cache_size,
+            end_after_zero: config.end_after_zero,
+        })
+        .collect();
+
+    info!("Starting a batch of {} appids.", specs.len());
+    // Default the pool width to the machine's parallelism; a single batch rarely
+    // wants more in-flight scrapes than cores.
+    let max_concurrency = std::thread::available_parallelism()
+        .map(|cores| cores.get())
+        .unwrap_or(4);
+    let batch = jobs::schedule(specs, max_concurrency);
+
+    // Drain events until the workers drop their senders and the channel closes.
+    for event in batch.events.iter() {
+        match event {
+            jobs::JobEvent::Started { appid } => info!("[{}] started.", appid),
+            jobs::JobEvent::Progress {
+                appid,
+                fetched,
+                total,
+                cursor,
+                rows_written,
+            } => info!(
+                "[{}] fetched {}/{} (rows written: {}, cursor: {}).",
+                appid,
+                fetched,
+                total.map_or_else(|| "?".to_owned(), |total| total.to_string()),
+                rows_written,
+                cursor.as_deref().unwrap_or("-"),
+            ),
+            jobs::JobEvent::Warning { appid, message } => warn!("[{}] {}", appid, message),
+            jobs::JobEvent::Finished {
+                appid,
+                rows_written,
+            } => info!("[{}] finished; {} rows written.", appid, rows_written),
+            jobs::JobEvent::Failed { appid, error } => error!("[{}] failed: {}", appid, error),
+        }
+    }
+
+    batch.join();
+    info!("Batch of {} appids complete.", config.batch.len());
+}
+
 fn build_scraper<IterMapFn>(matches: ArgMatches<'static>) -> ScraperAppSettings<IterMapFn>
 where
     IterMapFn: EndAfterZero,
 {
     let matches = build_arguments();
 
+    // Persisted presets sit underneath the CLI: a flag always wins, but anything
+    // the user didn't pass falls back to the config (and the config is generated
+    // with documented defaults on first run).
+    let config = Config::load_or_create().unwrap_or_else(|e| {
+        warn!("Couldn't load the config ({}); using built-in defaults.", e);
+        Config::default()
+    });
+
+    // Declarative batch mode runs the config's appid→output list through the job
+    // scheduler and drives it to completion here, so it never hands back a single
+    // `ScraperAppSettings` — just like the consolidated resume below.
+    if matches.is_present("batch") {
+        run_batch(&config);
+        std::process::exit(0);
+    }
+
     // Path to either resume a scrape or where to save a new one.
     // Output paths are required in all uses of my program so we can crash here.
-    let path = matches.value_of("OUTPUT").expect("Required output path not found. You need to pass a path to save the scrape's result (or to load a scrape to continue).");
-    let review_type =
-        matches
-            .value_of("review_type")
-            .map_or_else(ReviewType::default, |review_type| {
-                match review_type.to_lowercase().as_str() {
-                    "all" => ReviewType::All,
-                    "positive" => ReviewType::Positive,
-                    "negative" => ReviewType::Negative,
-                    _ => ReviewType::default(),
-                }
-            });
+    let raw_path = matches.value_of("OUTPUT").expect("Required output path not found. You need to pass a path to save the scrape's result (or to load a scrape to continue).");
+    // `--compress` is a convenience over the extension: when set, make sure the
+    // path carries the matching suffix so the cache's codec inference kicks in.
+    let output = compressed_output(raw_path, &matches);
+    let path = output.as_str();
+    let review_type = matches.value_of("review_type").map_or_else(
+        || config.review_type(),
+        |review_type| match review_type.to_lowercase().as_str() {
+            "all" => ReviewType::All,
+            "positive" => ReviewType::Positive,
+            "negative" => ReviewType::Negative,
+            _ => ReviewType::default(),
+        },
+    );
 
     // Ending after all duplicate data is optional. Using day_range requires Filter::All which "always" returns data according to the documentation. So, I'm not sure whether this
     // should be mandatory when resuming a scrape (i.e. because day_range and Filter::All are used with a cursor).
-    let end_after_zero = matches.is_present("end_after_zero");
+    let end_after_zero = matches.is_present("end_after_zero") || config.end_after_zero;
     // Whether to fail on an error during parsing a previous scrape.
     let scrape_n = matches.value_of("scrape_n").and_then(|n| {
         // Convert to an Option instead of a Result; panic if negative.
@@ -176,13 +342,11 @@ where
             }
         })
     });
-    let fail_on_error = matches.is_present("fail_on_error");
-    // Parse the cache size if any or return a default.
+    let fail_on_error = matches.is_present("fail_on_error") || config.fail_on_error;
+    // Parse the cache size if any or fall back to the config's value.
     let cache_size = matches
         .value_of("cache_size")
-        .map_or(DEFAULT_CACHE_SIZE, |s| {
-            s.parse().unwrap_or(DEFAULT_CACHE_SIZE)
-        });
+        .map_or(config.cache_size, |s| s.parse().unwrap_or(config.cache_size));
 
     // Logging useful informational bits
     info!("Using cache size: {}", cache_size);
@@ -199,45 +363,79 @@ where
     if matches.is_present("resume") {
         info!("Resuming a scrape using the file: {}", path);
 
-        // Build the cache by attempting to resume from the path.
         // It's okay to panic here during initialization because the program can't continue if the file loading fails.
-        let ResumeScraperCache { cache, resume_info } = match ScraperCache::resume_from_file(cache_size, path, fail_on_error) {
-            Ok(resume_scraper_cache) => resume_scraper_cache,
-            Err(Error::MultipleAppids) => panic!("The provided file ({}) contains multiple appids. Resuming multiple appids isn't supported.", path),
-            Err(Error::Io(e)) => io_error_handler(e.kind(), path),
-            Err(e) => panic!("Error while resuming scrape: {}", e)
-        };
 
-        // Calculate the number of days to go back based on the timestamps.
-        // I assume this works.
-        let last_scraped_time = Utc.timestamp(resume_info.timestamp.into(), 0);
-        let current_time = offset::Utc::now();
-        let days_ago = (current_time - last_scraped_time).num_days();
-
-        if days_ago.is_negative() {
-            panic!(
-                r#"The earliest timestamp in the provided file is more recent than today; check the provided file again.
-                   Last scraped time: {}
-                   Current time UTC: {}
-                   Elapsed days: {}"#,
-                last_scraped_time, current_time, days_ago
+        // Encrypted resumes stay single-appid: re-keying a consolidated,
+        // multi-game file is out of scope.
+        #[cfg(feature = "encrypt")]
+        if let Some(key) = encryption_key(&matches) {
+            let ResumeScraperCache { cache, resume_info } =
+                match ScraperCache::resume_from_file_encrypted(cache_size, path, fail_on_error, &key) {
+                    Ok(resumed) => resumed,
+                    Err(Error::Io(e)) => io_error_handler(e.kind(), path),
+                    Err(e) => panic!("Error while resuming scrape: {}", e),
+                };
+            let review_api = resume_api(
+                resume_info.appid.as_ref().parse().unwrap(),
+                resume_info.timestamp.into(),
+                review_type,
             );
-        } else {
-            let mut review_api = ReviewApi::new(resume_info.appid.as_ref().parse().unwrap());
-            review_api.filter(Filter::All)
-                .expect("Failed to change the Filter to Filter::All for resuming a scrape. This shouldn't happen.")
-                .day_range(days_ago.try_into().expect(&format!("days_ago can't fit into a u32 for some reason: {}", days_ago)))
-                .expect("Failed to set day_range while resuming a scrape; this is surely a bug.")
-                .num_per_page(100)
-                .review_type(review_type);
-
             let scraper: ReviewScraper = review_api
                 .try_into()
                 .expect("Error when building a scraper from the Steam API after parsing args.");
-
             let scraper = wrap_scraper(scraper, scrape_n, end_after_zero);
-            ScraperAppSettings { scraper, cache }
+            return ScraperAppSettings { scraper, cache };
         }
+
+        // Plaintext resume: partition by appid so a consolidated CSV spanning
+        // several games can be resumed rather than rejected outright.
+        let PartitionedResume { cache, resume_info } =
+            match ScraperCache::resume_from_file_partitioned(cache_size, path, fail_on_error) {
+                Ok(partitioned) => partitioned,
+                Err(Error::Io(e)) => io_error_handler(e.kind(), path),
+                Err(e) => panic!("Error while resuming scrape: {}", e),
+            };
+
+        if resume_info.len() > 1 {
+            // Drive every appid into the one shared cache and run to completion
+            // here; the cache flushes its tail on drop.
+            info!(
+                "Resuming {} appids from {} into one consolidated file.",
+                resume_info.len(),
+                path
+            );
+            let scrapers: Vec<ReviewScraper> = resume_info
+                .into_iter()
+                .map(|(appid, info)| {
+                    let api = resume_api(appid.parse().unwrap(), info.timestamp.into(), review_type);
+                    api.try_into()
+                        .expect("Error when building a scraper from the Steam API after parsing args.")
+                })
+                .collect();
+
+            let mut cache = cache;
+            let rows = jobs::run_resume_consolidated(scrapers, &mut cache, end_after_zero);
+            info!("Consolidated resume finished; {} rows written.", rows);
+            // Nothing left to hand back to the single-scraper caller.
+            std::process::exit(0);
+        }
+
+        // Exactly one appid (or an empty file): behave like the classic resume.
+        let resume_info = resume_info
+            .into_iter()
+            .map(|(_, info)| info)
+            .next()
+            .unwrap_or_default();
+        let review_api = resume_api(
+            resume_info.appid.as_ref().parse().unwrap(),
+            resume_info.timestamp.into(),
+            review_type,
+        );
+        let scraper: ReviewScraper = review_api
+            .try_into()
+            .expect("Error when building a scraper from the Steam API after parsing args.");
+        let scraper = wrap_scraper(scraper, scrape_n, end_after_zero);
+        ScraperAppSettings { scraper, cache }
     } else {
         let appid = matches
             .value_of("appid")
@@ -251,7 +449,15 @@ where
             .try_into()
             .expect("Error when building a scraper from the Steam API after parsing args.");
 
-        let cache = match ScraperCache::new(cache_size, path) {
+        #[cfg(feature = "encrypt")]
+        let cache_result = match encryption_key(&matches) {
+            Some(key) => ScraperCache::new_encrypted(cache_size, path, &key),
+            None => ScraperCache::new(cache_size, path),
+        };
+        #[cfg(not(feature = "encrypt"))]
+        let cache_result = ScraperCache::new(cache_size, path);
+
+        let cache = match cache_result {
             Ok(cache) => cache,
             Err(Error::Io(e)) => io_error_handler(e.kind(), path),
             Err(e) => panic!("Error when building scraper cache: {}", e),
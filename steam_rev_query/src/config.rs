@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    env, fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+use steam_review_api::ReviewType;
+use tracing::{info, warn};
+
+// Where the config lives under the user's config home. Mirrors the XDG layout
+// (`$XDG_CONFIG_HOME` or `~/.config`) so the file lands somewhere predictable.
+const CONFIG_DIR: &str = "steam_review_scraper";
+const CONFIG_FILE: &str = "config.toml";
+
+// Default cache size shared with arguments.rs. Kept in one place so the generated
+// config and the CLI fall back to the same number.
+pub const DEFAULT_CACHE_SIZE: usize = 500;
+
+// Documented template written out verbatim on first run. Serializing a Config
+// loses the comments, so the defaults are spelled out by hand the way a
+// hand-maintained dotfile would be.
+const DEFAULT_CONFIG: &str = r#"# steam_review_scraper configuration.
+# CLI flags always override the values set here.
+
+# Number of reviews buffered in memory before a flush to disk.
+# Lower means more frequent writes; higher means more memory use.
+cache_size = 500
+
+# Which reviews to scrape: "all", "positive", or "negative".
+review_type = "all"
+
+# Stop a scrape once a batch comes back as all duplicates. Handy with --resume.
+end_after_zero = false
+
+# Fail instead of skipping a row when a previous scrape can't be parsed on resume.
+fail_on_error = false
+
+# Declarative scrape batch. Each entry pairs an appid with the file to write it to.
+# Uncomment and fill in to drive a whole run without retyping flags:
+#
+# [[batch]]
+# appid = 379720
+# output = "doom.csv"
+#
+# [[batch]]
+# appid = 372360
+# output = "symphonia.csv"
+"#;
+
+/// A single appid → output-path mapping from the declarative batch list.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchEntry {
+    pub appid: u32,
+    pub output: PathBuf,
+}
+
+/// Persisted scrape presets merged underneath the CLI.
+///
+/// The knobs mirror what `build_scraper` reads from clap so a user can set them
+/// once in the config and only reach for flags when overriding a preset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub cache_size: usize,
+    pub review_type: String,
+    pub end_after_zero: bool,
+    pub fail_on_error: bool,
+    pub batch: Vec<BatchEntry>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            cache_size: DEFAULT_CACHE_SIZE,
+            review_type: "all".to_owned(),
+            end_after_zero: false,
+            fail_on_error: false,
+            batch: Vec::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Path to the config file, honoring `$XDG_CONFIG_HOME` then `$HOME/.config`.
+    pub fn default_path() -> io::Result<PathBuf> {
+        let base = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .filter(|path| !path.as_os_str().is_empty())
+            .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+            .ok_or_else(|| {
+                io::Error::new(
+                    ErrorKind::NotFound,
+                    "Neither XDG_CONFIG_HOME nor HOME is set; can't locate a config directory.",
+                )
+            })?;
+
+        Ok(base.join(CONFIG_DIR).join(CONFIG_FILE))
+    }
+
+    /// Load the config from the standard location, generating a documented
+    /// default on first use.
+    pub fn load_or_create() -> io::Result<Self> {
+        let path = Self::default_path()?;
+        Self::load_or_create_at(path)
+    }
+
+    /// Like [`Config::load_or_create`] but against an explicit path (used by tests
+    /// and callers that want a non-standard location).
+    pub fn load_or_create_at<P>(path: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        match fs::read_to_string(path) {
+            Ok(raw) => Self::parse(path, &raw),
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                info!("No config found; writing a default to {}.", path.display());
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                fs::write(path, DEFAULT_CONFIG)?;
+                Ok(Self::default())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    // Parse TOML or JSON based on the file extension, defaulting to TOML.
+    fn parse(path: &Path, raw: &str) -> io::Result<Self> {
+        let invalid = |e: &dyn std::fmt::Display| {
+            io::Error::new(
+                ErrorKind::InvalidData,
+                format!("Couldn't parse config at {}: {}", path.display(), e),
+            )
+        };
+
+        if path.extension().and_then(|ext| ext.to_str()) == Some("json") {
+            serde_json::from_str(raw).map_err(|e| invalid(&e))
+        } else {
+            toml::from_str(raw).map_err(|e| invalid(&e))
+        }
+    }
+
+    /// Resolve the configured review type, falling back to the default for an
+    /// unrecognized string (matching how the CLI parses the flag).
+    pub fn review_type(&self) -> ReviewType {
+        match self.review_type.to_lowercase().as_str() {
+            "all" => ReviewType::All,
+            "positive" => ReviewType::Positive,
+            "negative" => ReviewType::Negative,
+            other => {
+                warn!("Unknown review_type {:?} in config; using the default.", other);
+                ReviewType::default()
+            }
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use std::{
+    collections::VecDeque,
+    convert::TryInto,
+    path::PathBuf,
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+use chrono::Utc;
+use rev_query_utils::{error::Error, resumeinfo::ResumeInfo};
+use review_scraper::ReviewScraper;
+use scraper_cache::ScraperCache;
+use steam_review_api::{Filter, ReviewApi, ReviewType};
+use tracing::{error, info, warn};
+
+// Sidecar checkpoint for an output file, e.g. `doom.csv` -> `doom.checkpoint`.
+fn checkpoint_path(output: &std::path::Path) -> PathBuf {
+    output.with_extension("checkpoint")
+}
+
+// Build a job's API, resuming from a persisted checkpoint when one exists for
+// this appid so a killed run picks up near the last committed cursor.
+fn build_start_api(appid: u32, review_type: ReviewType, checkpoint: &std::path::Path) -> ReviewApi {
+    let mut api = ReviewApi::new(appid);
+    api.num_per_page(100).review_type(review_type);
+
+    if let Ok(info) = ResumeInfo::load(checkpoint) {
+        if info.appid.as_ref() == appid.to_string() {
+            let days = (Utc::now() - info.timestamp.to_datetime()).num_days();
+            if days >= 0 {
+                info!("Resuming {} from checkpoint ({} days back).", appid, days);
+                // Filter::All + day_range replays recent reviews through the cursor.
+                let _ = api
+                    .filter(Filter::All)
+                    .and_then(|api| api.day_range(days as u32));
+            }
+        }
+    }
+    api
+}
+
+/// Resume a consolidated multi-appid scrape into one shared cache.
+///
+/// Every appid's scraper appends to the single [`ScraperCache`] produced by
+/// [`scraper_cache::ScraperCache::resume_from_file_partitioned`], so a CSV that
+/// holds several games resumes into the same file. Unlike [`schedule`], this runs
+/// on the calling thread: the scrapers and cache hold `Rc`-backed titles and so
+/// are `!Send`, which is exactly why [`schedule`] builds each per-appid scraper
+/// and cache inside its own worker. Errors are isolated per appid so one failing
+/// game doesn't abort the rest; `end_after_zero` stops an appid after an
+/// all-duplicate page. Returns the total rows handed to the cache.
+pub fn run_resume_consolidated(
+    scrapers: Vec<ReviewScraper>,
+    cache: &mut ScraperCache,
+    end_after_zero: bool,
+) -> usize {
+    let mut total_rows = 0usize;
+    for mut scraper in scrapers {
+        // The scraper stores the appid as a string; fall back to 0 if it somehow
+        // isn't numeric so log lines still carry a tag.
+        let appid: u32 = scraper.appid().parse().unwrap_or(0);
+        info!("Resuming appid {} into the consolidated file.", appid);
+
+        loop {
+            match scraper.next() {
+                Some(Ok(batch)) => match cache.insert(&batch) {
+                    Ok(()) => total_rows += batch.len(),
+                    Err(Error::NoDataAfterFiltering) => {
+                        warn!("A page for {} came back as all duplicates.", appid);
+                        if end_after_zero {
+                            break;
+                        }
+                    }
+                    Err(e) => warn!("Skipping a bad batch for {}: {}", appid, e),
+                },
+                // This appid is exhausted; move on to the next.
+                None => break,
+                // Isolate the failure to this appid so the others still resume.
+                Some(Err(e)) => {
+                    error!("Resume of appid {} failed: {}", appid, e);
+                    break;
+                }
+            }
+        }
+    }
+
+    total_rows
+}
+
+/// One appid's worth of work: what to scrape and where to write it.
+///
+/// A job owns its own [`ScraperCache`] and CSV so the pool can run several at
+/// once without contending on a shared writer.
+#[derive(Debug, Clone)]
+pub struct JobSpec {
+    pub appid: u32,
+    pub output: PathBuf,
+    pub review_type: ReviewType,
+    pub cache_size: usize,
+    // Stop this job once a page comes back as all duplicates, mirroring the CLI's
+    // `--end-after-no-new-data`.
+    pub end_after_zero: bool,
+}
+
+/// Progress and lifecycle events emitted per appid so a log/UI consumer can render
+/// per-job ETAs and surface non-fatal problems without the batch aborting.
+#[derive(Debug, Clone)]
+pub enum JobEvent {
+    Started {
+        appid: u32,
+    },
+    Progress {
+        appid: u32,
+        // Reviews pulled from the API so far (before dedup).
+        fetched: usize,
+        // Steam's reported total for the query, if it sent one.
+        total: Option<u32>,
+        // Cursor of the most recent page.
+        cursor: Option<String>,
+        // Rows handed to the cache so far.
+        rows_written: usize,
+    },
+    // A non-fatal hiccup (a filtered-out page, a skipped row). The job keeps going.
+    Warning {
+        appid: u32,
+        message: String,
+    },
+    Finished {
+        appid: u32,
+        rows_written: usize,
+    },
+    // A fatal error for this appid only; other jobs carry on.
+    Failed {
+        appid: u32,
+        error: String,
+    },
+}
+
+/// A running batch: the event stream plus a handle to the worker pool.
+///
+/// An interrupted run doesn't need an explicit stop signal — each job flushes a
+/// rolling checkpoint after every page (see [`run_job`]), so a killed batch picks
+/// back up from the last committed cursor on the next `--resume`.
+pub struct Batch {
+    pub events: Receiver<JobEvent>,
+    pool: JoinHandle<()>,
+}
+
+impl Batch {
+    /// Wait for the pool to drain. The event receiver closes once this returns.
+    pub fn join(self) {
+        if self.pool.join().is_err() {
+            error!("A scrape worker thread panicked.");
+        }
+    }
+}
+
+/// Spawn a bounded pool over `specs`, capped at `max_concurrency` jobs in flight.
+///
+/// Returns immediately with a [`Batch`] whose `events` receiver streams
+/// [`JobEvent`]s as the pool makes progress. Each job builds its own scraper and
+/// cache inside its worker thread, so nothing `!Send` crosses a thread boundary.
+pub fn schedule(specs: Vec<JobSpec>, max_concurrency: usize) -> Batch {
+    let (tx, rx) = mpsc::channel();
+    let queue = Arc::new(Mutex::new(VecDeque::from(specs)));
+
+    // One fewer worker than jobs is pointless; one more is wasted. Clamp to at
+    // least one so an empty/odd request still terminates cleanly.
+    let workers = max_concurrency.max(1).min(queue.lock().unwrap().len().max(1));
+
+    let pool = thread::spawn(move || {
+        let mut handles = Vec::with_capacity(workers);
+        for _ in 0..workers {
+            let queue = Arc::clone(&queue);
+            let tx = tx.clone();
+            handles.push(thread::spawn(move || worker(queue, tx)));
+        }
+        // Drop the spare sender so the receiver closes once the workers finish.
+        drop(tx);
+        for handle in handles {
+            if handle.join().is_err() {
+                error!("A scrape worker thread panicked.");
+            }
+        }
+    });
+
+    Batch { events: rx, pool }
+}
+
+// Pull specs off the shared queue until it's empty.
+fn worker(queue: Arc<Mutex<VecDeque<JobSpec>>>, tx: Sender<JobEvent>) {
+    loop {
+        let spec = {
+            let mut queue = queue.lock().unwrap();
+            queue.pop_front()
+        };
+        match spec {
+            Some(spec) => run_job(spec, &tx),
+            None => break,
+        }
+    }
+}
+
+// Drive a single appid to completion, reporting progress as pages arrive.
+fn run_job(spec: JobSpec, tx: &Sender<JobEvent>) {
+    let appid = spec.appid;
+    let _ = tx.send(JobEvent::Started { appid });
+
+    let checkpoint = checkpoint_path(&spec.output);
+    let scraper: ReviewScraper = match build_start_api(appid, spec.review_type, &checkpoint).try_into()
+    {
+        Ok(scraper) => scraper,
+        Err(e) => {
+            let _ = tx.send(JobEvent::Failed {
+                appid,
+                error: e.to_string(),
+            });
+            return;
+        }
+    };
+
+    let mut cache = match ScraperCache::new(spec.cache_size, &spec.output) {
+        Ok(cache) => cache,
+        Err(e) => {
+            let _ = tx.send(JobEvent::Failed {
+                appid,
+                error: e.to_string(),
+            });
+            return;
+        }
+    };
+
+    let mut scraper = scraper;
+    let mut fetched = 0usize;
+    let mut rows_written = 0usize;
+    // Rolling checkpoint flushed after each page so an interrupted job resumes
+    // from the oldest review it has already committed.
+    let mut resume_info = ResumeInfo::default();
+
+    loop {
+        match scraper.next() {
+            Some(Ok(batch)) => {
+                fetched += batch.len();
+                // Fold the page into the checkpoint and persist it before the
+                // next fetch so the cursor survives a crash.
+                for query in &batch {
+                    resume_info.update(query);
+                }
+                if let Err(e) = resume_info.save(&checkpoint) {
+                    warn!("Couldn't write the checkpoint for {}: {}", appid, e);
+                }
+                match cache.insert(&batch) {
+                    Ok(()) => rows_written += batch.len(),
+                    // All duplicates: honor end_after_zero, otherwise keep paging.
+                    Err(Error::NoDataAfterFiltering) => {
+                        let _ = tx.send(JobEvent::Warning {
+                            appid,
+                            message: "A page came back as all duplicates.".to_owned(),
+                        });
+                        if spec.end_after_zero {
+                            break;
+                        }
+                    }
+                    // A bad row shouldn't sink the whole batch; warn and move on.
+                    Err(e) => {
+                        let _ = tx.send(JobEvent::Warning {
+                            appid,
+                            message: e.to_string(),
+                        });
+                    }
+                }
+
+                let _ = tx.send(JobEvent::Progress {
+                    appid,
+                    fetched,
+                    total: scraper.total_reviews(),
+                    cursor: scraper.last_cursor().map(str::to_owned),
+                    rows_written,
+                });
+            }
+            // Iterator exhausted: the appid is done.
+            None => break,
+            // A transport/parse error for this appid. Surface it and stop this job.
+            Some(Err(e)) => {
+                let _ = tx.send(JobEvent::Failed {
+                    appid,
+                    error: e.to_string(),
+                });
+                return;
+            }
+        }
+    }
+
+    // The cache flushes its tail on Drop, but flush explicitly so a late IO error
+    // is reported rather than swallowed.
+    if let Err(e) = cache.flush_cache() {
+        warn!("Final flush for {} failed: {}", appid, e);
+    }
+    let _ = tx.send(JobEvent::Finished {
+        appid,
+        rows_written,
+    });
+}
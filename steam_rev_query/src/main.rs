@@ -1,13 +1,18 @@
 mod arguments;
+mod config;
+mod jobs;
 use arguments::ScraperAppSettings;
 use rev_query_utils::error::Error;
 use review_scraper::ReviewScraper;
 
-use tracing::{info, warn};
+use tracing::{info, warn, Level};
 
 fn main() {
-    // Uses the RUST_LOG environmental variable like other loggers.
-    tracing_subscriber::fmt::init();
+    // `--debug` bumps the floor to DEBUG so the cache pipeline's timing spans
+    // surface; otherwise we stay at INFO and still honor RUST_LOG.
+    let debug = std::env::args().any(|arg| arg == "--debug" || arg == "-d");
+    let level = if debug { Level::DEBUG } else { Level::INFO };
+    tracing_subscriber::fmt().with_max_level(level).init();
 
     let test = ScraperAppSettings::from_arguments();
 }
@@ -4,7 +4,7 @@ use url::{ParseError, Url};
 use crate::{
     error::RevApiError,
     language::Language,
-    options::{Filter, PurchaseType, ReviewType},
+    options::{DayRange, Filter, PurchaseType, ReviewType},
 };
 
 const STEAM_REV_API: &str = "https://store.steampowered.com/appreviews/";
@@ -12,7 +12,7 @@ const STEAM_REV_API: &str = "https://store.steampowered.com/appreviews/";
 /// State information/builder for the Steam review A.P.I.
 ///
 /// https://partner.steamgames.com/doc/store/getreviews
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ReviewApi<'val> {
     /// Stores query pairs as key, value to parse with the url crate.
     query: HashMap<&'static str, Cow<'val, str>>,
@@ -98,9 +98,37 @@ impl<'val> ReviewApi<'val> {
         ("json", "1")
     }
 
-    /// Request reviews in a specific language. Currently not settable via my implementation.
-    fn add_language(lang: Language) -> (&'static str, &'static str) {
-        ("language", lang.as_str())
+    /// Request reviews in a specific language. Used to seed the default in `new`.
+    fn add_language(_lang: Language) -> (&'static str, &'static str) {
+        // Only ever called with English in the constructor; the variant is taken
+        // so callers keep reading intent, but a static str keeps the default cheap
+        // now that `Language::as_str` borrows (thanks to `Language::Unknown`).
+        ("language", "english")
+    }
+
+    /// Request reviews in a specific language, overriding the English default.
+    ///
+    /// The `Review` struct deserializes a per-review language, so leaving the
+    /// default in place silently discards every non-English review. Pass
+    /// [`Language::All`] to ask Steam for every language it stores — handy for
+    /// multilingual review research where the English slice isn't enough.
+    ///
+    /// ## Overwrite
+    /// This function overwrites any previously set language.
+    ///
+    /// ## Example
+    /// ```rust
+    /// use steam_review_api::{Language, ReviewApi};
+    ///
+    /// let mut builder = ReviewApi::new(374320);
+    /// builder.language(Language::All);
+    /// ```
+    pub fn language(&mut self, lang: Language) -> &mut Self {
+        // as_str borrows `lang` now, so own the string into the Cow.
+        self.query
+            .entry("language")
+            .insert(lang.as_str().to_owned().into());
+        self
     }
 
     /// Return results in a specific order such as by most recent.
@@ -158,6 +186,27 @@ impl<'val> ReviewApi<'val> {
         }
     }
 
+    /// Set the `day_range` from a bounded [`DayRange`] span rather than a raw
+    /// integer. The span is resolved to the single lookback integer Steam wants.
+    ///
+    /// Same rules as [`ReviewApi::day_range`]: only valid with `Filter::All`.
+    #[inline]
+    pub fn day_range_span(&mut self, range: DayRange) -> Result<&mut Self, RevApiError> {
+        self.day_range(range.days())
+    }
+
+    /// Include reviews Steam flags as off-topic review bombs.
+    ///
+    /// Steam hides off-topic review activity by default. Passing `true` here sets
+    /// `filter_offtopic_activity=1` so those reviews are returned as well, which
+    /// matters when the bomb itself is the object of study.
+    pub fn filter_offtopic_activity(&mut self, include: bool) -> &mut Self {
+        self.query
+            .entry("filter_offtopic_activity")
+            .insert(if include { "1" } else { "0" }.into());
+        self
+    }
+
     pub fn change_cursor(&mut self, new_cursor: &'val str) -> Result<&mut Self, RevApiError> {
         if self.paging_ok() {
             self.query.entry("cursor").insert(new_cursor.into());
@@ -204,6 +253,9 @@ impl<'val> ReviewApi<'val> {
     /// builder.num_per_page(100);
     /// ```
     pub fn num_per_page(&mut self, amount: u8) -> &mut Self {
+        // Steam caps a page at 100 and returns the maximum rather than failing,
+        // but capping here keeps the built URL honest about what we asked for.
+        let amount = amount.min(100);
         self.query
             .entry("num_per_page")
             .insert(amount.to_string().into());
@@ -261,6 +313,65 @@ impl<'val> ReviewApi<'val> {
 
         Url::parse_with_params(&base_query.as_str(), self.query.iter())
     }
+
+    /// Validate the current builder state before building the [`Url`].
+    ///
+    /// The individual setters already reject most invalid combinations as they
+    /// happen, but a caller who pokes at the builder in an unusual order (or who
+    /// wants a single gate before firing a request) can call this to re-check the
+    /// two invariants the error messages describe:
+    ///
+    /// * a `day_range` is only valid with `Filter::All`, and
+    /// * a non-default cursor is only valid with `Filter::Recent`/`Filter::Updated`.
+    ///
+    /// ## Errors
+    /// Returns [`RevApiError::InvalidFilterDayRange`] or
+    /// [`RevApiError::InvalidFilterCursor`] for the respective invalid states.
+    /// On a valid state the query is built; an internal `ParseError` from
+    /// [`build`](ReviewApi::build) (which shouldn't happen) panics here.
+    pub fn try_build(&self) -> Result<Url, RevApiError> {
+        let filter = &**self
+            .query
+            .get("filter")
+            .expect("Unexpected: Filter is always set so you shouldn't see this message.");
+
+        if self.query.contains_key("day_range") && filter != "all" {
+            return Err(RevApiError::InvalidFilterDayRange);
+        }
+
+        let cursor_is_default = self
+            .query
+            .get("cursor")
+            .map_or(true, |cursor| &**cursor == "*");
+        if !cursor_is_default && !matches!(filter, "recent" | "updated") {
+            return Err(RevApiError::InvalidFilterCursor);
+        }
+
+        Ok(self
+            .build()
+            .expect("Unexpected: a validated query should always parse into a Url."))
+    }
+
+    /// Build one [`Url`] per requested language for a cross-language fan-out.
+    ///
+    /// Each URL is this query with its `language` param overridden, paired with the
+    /// [`Language`] it was built for so results can be tagged back by the caller
+    /// (the pagination layer uses this to pull a full cross-language corpus for an
+    /// appid in one call instead of N hand-built builders).
+    pub fn build_languages(
+        &self,
+        languages: &[Language],
+    ) -> Result<Vec<(Language, Url)>, ParseError> {
+        languages
+            .iter()
+            .map(|language| {
+                let language = language.clone();
+                let mut per_lang = self.clone();
+                per_lang.language(language.clone());
+                per_lang.build().map(|url| (language, url))
+            })
+            .collect()
+    }
 }
 
 impl TryFrom<&ReviewApi<'_>> for Url {
@@ -316,4 +427,32 @@ mod tests {
             .expect("Filter is set to All yet day_range() failed.")
             .build().expect("I broke build().");
     }
+
+    #[test]
+    fn day_range_span_caps_at_a_year() {
+        let mut steam = ReviewApi::new(311690);
+        steam
+            .filter(Filter::All)
+            .expect("Changing the Filter right after constructing shouldn't raise an error.")
+            .day_range_span(DayRange::Span { from: 10, to: 9001 })
+            .expect("Filter is All yet day_range_span() failed.");
+        assert_eq!(steam.query.get("day_range").map(|d| &**d), Some("365"));
+    }
+
+    #[test]
+    fn try_build_rejects_cursor_with_all() {
+        // A non-default cursor with Filter::All is invalid, and the setters refuse
+        // to reach that state, so the filter is swapped underneath below.
+        let mut steam = ReviewApi::new(584400);
+        steam
+            .change_cursor("notdefault")
+            .expect("Default filter is Recent so a cursor is fine here.")
+            .filter(Filter::Updated)
+            .expect("Updated keeps the cursor valid.");
+        // Force the state try_build() guards against by swapping the filter underneath.
+        steam.query.entry("filter").insert("all".into());
+        steam
+            .try_build()
+            .expect_err("A non-default cursor with Filter::All should fail try_build().");
+    }
 }
@@ -0,0 +1,155 @@
+//! Feature-gated HTTP execution layer.
+//!
+//! [`ReviewApi::build`] only produces a [`Url`]; actually fetching it is left to
+//! the caller. [`ReviewClient`] closes that gap: hand it a built URL and it runs
+//! the request and deserializes the body into a [`SteamRevOuter`]. The network
+//! call itself is routed through a user-supplied [`RequestHandler`] so people can
+//! inject their own `reqwest` client, attach auth headers, log, or swap in a mock
+//! for tests — a way to "hack into" the request pipeline.
+//!
+//! Because Steam throttles `appreviews`, the client paces outgoing requests to a
+//! configurable rate and retries HTTP 429 with backoff so bulk collection across
+//! many appids doesn't trip the limiter.
+//!
+//! Enable the `client` feature to pull this in.
+
+use std::time::Duration;
+
+use futures_util::future::BoxFuture;
+use reqwest::{Client, RequestBuilder, Response};
+use tokio::time::{sleep, Instant};
+use url::Url;
+
+use crate::convenience_structs::SteamRevOuter;
+
+/// Error surface for the execution layer.
+#[derive(Debug)]
+pub enum ClientError {
+    Request(reqwest::Error),
+    /// Steam kept returning 429 past the configured retry budget.
+    RateLimited { attempts: u32 },
+}
+
+impl std::error::Error for ClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ClientError::Request(e) => Some(e),
+            ClientError::RateLimited { .. } => None,
+        }
+    }
+}
+
+impl std::fmt::Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::Request(e) => e.fmt(f),
+            ClientError::RateLimited { attempts } => {
+                write!(f, "Steam kept rate limiting us after {} attempts.", attempts)
+            }
+        }
+    }
+}
+
+impl From<reqwest::Error> for ClientError {
+    #[inline]
+    fn from(error: reqwest::Error) -> Self {
+        ClientError::Request(error)
+    }
+}
+
+type Result<T> = std::result::Result<T, ClientError>;
+
+/// A hook into the request pipeline.
+///
+/// The handler receives the prepared [`RequestBuilder`] and returns the eventual
+/// [`Response`]. The default handler just sends it, but a caller can wrap it to
+/// add headers, record fixtures, or fail deterministically in tests.
+pub type RequestHandler =
+    Box<dyn Fn(RequestBuilder) -> BoxFuture<'static, reqwest::Result<Response>> + Send + Sync>;
+
+/// Executes built [`Url`]s against Steam with pacing and 429 retries.
+pub struct ReviewClient {
+    client: Client,
+    handler: RequestHandler,
+    // Minimum spacing between two outgoing requests.
+    min_interval: Duration,
+    // When the next request is allowed to go out.
+    next_allowed: Instant,
+    // How many times to retry a 429 before giving up.
+    max_retries: u32,
+    // Base backoff delay, doubled on each retry.
+    backoff_base: Duration,
+}
+
+impl ReviewClient {
+    /// Build a client that paces requests to at most `requests_per_second`.
+    pub fn new(requests_per_second: u32) -> Self {
+        Self::with_handler(requests_per_second, Box::new(|request| Box::pin(request.send())))
+    }
+
+    /// Build a client with a custom [`RequestHandler`].
+    pub fn with_handler(requests_per_second: u32, handler: RequestHandler) -> Self {
+        let rps = requests_per_second.max(1);
+        Self {
+            client: Client::new(),
+            handler,
+            min_interval: Duration::from_secs(1) / rps,
+            next_allowed: Instant::now(),
+            max_retries: 5,
+            backoff_base: Duration::from_millis(500),
+        }
+    }
+
+    /// Override the maximum number of 429 retries (default 5).
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    // Wait until the rate limiter lets the next request through, then reserve the
+    // following slot.
+    async fn pace(&mut self) {
+        let now = Instant::now();
+        if self.next_allowed > now {
+            sleep(self.next_allowed - now).await;
+        }
+        self.next_allowed = Instant::now() + self.min_interval;
+    }
+
+    /// Fetch and deserialize a single page.
+    ///
+    /// Honours the configured request rate and retries HTTP 429 with exponential
+    /// backoff, preferring the server's `Retry-After` header when present.
+    pub async fn fetch(&mut self, url: Url) -> Result<SteamRevOuter> {
+        let mut attempt = 0;
+        loop {
+            self.pace().await;
+
+            let request = self.client.get(url.clone());
+            let response = (self.handler)(request).await?;
+
+            if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                attempt += 1;
+                if attempt > self.max_retries {
+                    return Err(ClientError::RateLimited { attempts: attempt });
+                }
+                sleep(self.retry_delay(attempt, &response)).await;
+                continue;
+            }
+
+            return Ok(response.error_for_status()?.json::<SteamRevOuter>().await?);
+        }
+    }
+
+    // Prefer Retry-After (seconds) if Steam sent one, otherwise exponential
+    // backoff off the base delay.
+    fn retry_delay(&self, attempt: u32, response: &Response) -> Duration {
+        response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(Duration::from_secs)
+            .unwrap_or_else(|| self.backoff_base * 2u32.pow(attempt - 1))
+    }
+}
@@ -63,3 +63,40 @@ impl Display for UnixTimestamp {
         self.0.fmt(f)
     }
 }
+
+// chrono-backed date arithmetic. A raw epoch `u64` is awkward for the research
+// use case this crate serves, so the `chrono` feature adds real `DateTime<Utc>`
+// conversions and an RFC3339 path like twitch_api2's validated `Timestamp`.
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use super::UnixTimestamp;
+    use chrono::{DateTime, TimeZone, Utc};
+
+    impl UnixTimestamp {
+        /// Interpret the stored epoch seconds as a UTC datetime.
+        #[inline]
+        pub fn to_datetime(self) -> DateTime<Utc> {
+            // Steam's timestamps are non-negative and well within range, so the
+            // conversion can't realistically fail.
+            Utc.timestamp(self.0 as i64, 0)
+        }
+
+        /// Build a `UnixTimestamp` from a UTC datetime, truncating sub-second parts.
+        #[inline]
+        pub fn from_datetime(datetime: DateTime<Utc>) -> Self {
+            UnixTimestamp(datetime.timestamp() as u64)
+        }
+
+        /// RFC3339 rendering of the timestamp (e.g. `2021-04-11T18:35:12+00:00`).
+        #[inline]
+        pub fn to_rfc3339(self) -> String {
+            self.to_datetime().to_rfc3339()
+        }
+
+        /// Parse an RFC3339 string into a `UnixTimestamp`.
+        pub fn from_rfc3339(s: &str) -> Result<Self, chrono::ParseError> {
+            DateTime::parse_from_rfc3339(s)
+                .map(|dt| UnixTimestamp(dt.timestamp() as u64))
+        }
+    }
+}
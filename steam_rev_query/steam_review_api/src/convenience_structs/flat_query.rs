@@ -144,6 +144,11 @@ pub struct FlattenedQuery {
     pub received_for_free: bool,
     pub written_during_early_access: bool,
     pub developer_response: Cow<'static, str>,
+    /// Unknown keys carried over from a [`ParsedReview::Dynamic`] record, stored
+    /// as their raw JSON text. Flattened into the record so fields Valve shipped
+    /// after this crate was built still reach the export as their own columns.
+    #[serde(flatten)]
+    pub extra: std::collections::BTreeMap<String, String>,
 }
 
 impl From<Review> for FlattenedQuery {
@@ -167,19 +172,55 @@ impl From<Review> for FlattenedQuery {
             received_for_free: other.received_for_free,
             written_during_early_access: other.written_during_early_access,
             developer_response: other.developer_response.map_or("".into(), Into::into),
+            extra: std::collections::BTreeMap::new(),
+        }
+    }
+}
+
+impl Default for FlattenedQuery {
+    /// An empty row. Used as the base when rebuilding a record from a
+    /// [`crate::convenience_structs::ParsedReview::Dynamic`] payload whose known
+    /// fields couldn't be recovered typed; `language` falls back to English.
+    fn default() -> Self {
+        Self {
+            title: TitleSerde::default(),
+            appid: TitleSerde::default(),
+            recommendation_id: 0,
+            steam_id: 0,
+            num_games_owned: 0,
+            num_reviews: 0,
+            playtime_forever: Minutes(0),
+            language: Language::English,
+            review: String::new(),
+            timestamp_created: UnixTimestamp(0),
+            voted_up: false,
+            votes_up: 0,
+            votes_funny: 0,
+            comment_count: 0,
+            steam_purchase: false,
+            received_for_free: false,
+            written_during_early_access: false,
+            developer_response: "".into(),
+            extra: std::collections::BTreeMap::new(),
         }
     }
 }
 
 impl FlattenedQuery {
-    pub fn from_with_title_strs(other: Review, title: Rc<str>, appid: Rc<str>) -> Self {
+    pub fn from_with_title_strs<R>(other: R, title: Rc<str>, appid: Rc<str>) -> Self
+    where
+        R: Into<FlattenedQuery>,
+    {
         let mut query: Self = other.into();
         query.title = title.into();
         query.appid = appid.into();
         query
     }
 
-    pub fn from_with_titles(other: Review, title: TitleSerde, appid: TitleSerde) -> Self {
+    pub fn from_with_titles<R>(other: R, title: TitleSerde, appid: TitleSerde) -> Self
+    where
+        R: Into<FlattenedQuery>,
+    {
         let mut query: Self = other.into();
         query.title = title;
         query.appid = appid;
@@ -2,9 +2,11 @@
 
 mod conv_newtypes;
 pub mod flat_query;
+mod parsed;
 mod query_structs;
 mod reviewscore;
 
 pub use conv_newtypes::*;
+pub use parsed::ParsedReview;
 pub use query_structs::*;
 pub use reviewscore::*;
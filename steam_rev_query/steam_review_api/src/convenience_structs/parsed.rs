@@ -0,0 +1,112 @@
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+use super::{flat_query::FlattenedQuery, query_structs::Review};
+
+/// A review parsed either into the known [`Review`] schema or, when Steam ships a
+/// field this crate hasn't caught up with, left as the raw JSON object.
+///
+/// A field that changes type or goes missing fails the whole `Review`
+/// deserialize, and a field `Review` doesn't declare is silently dropped by it.
+/// Mirroring the TypeSafe/Dynamic split, `ParsedReview` keeps the batch flowing:
+/// such a record lands as [`ParsedReview::Dynamic`] so callers can inspect or
+/// repair it instead of losing it.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParsedReview {
+    /// The payload matched the known schema, with no top-level keys outside it.
+    Typed(Review),
+    /// The payload didn't match; the raw object is preserved verbatim.
+    Dynamic(Map<String, Value>),
+}
+
+impl<'de> Deserialize<'de> for ParsedReview {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Deserialize into a Value first, then try the typed path and fall back to
+        // Dynamic on any mismatch.
+        let value = Value::deserialize(deserializer)?;
+        // serde ignores keys `Review` doesn't declare, so check for them here; a
+        // record carrying one stays Dynamic and keeps the new field.
+        let has_unknown_key = value.as_object().map_or(false, |map| {
+            map.keys().any(|key| !KNOWN_KEYS.contains(&key.as_str()))
+        });
+        match serde_json::from_value::<Review>(value.clone()) {
+            Ok(review) if !has_unknown_key => Ok(ParsedReview::Typed(review)),
+            _ => match value {
+                Value::Object(map) => Ok(ParsedReview::Dynamic(map)),
+                // A non-object isn't even review-shaped; keep it as a single-key map
+                // so nothing is silently dropped.
+                other => {
+                    let mut map = Map::new();
+                    map.insert("_raw".to_owned(), other);
+                    Ok(ParsedReview::Dynamic(map))
+                }
+            },
+        }
+    }
+}
+
+impl ParsedReview {
+    /// The review's `recommendationid`, if recoverable.
+    ///
+    /// Always present for [`ParsedReview::Typed`]; parsed best-effort from the raw
+    /// map for [`ParsedReview::Dynamic`], where Steam encodes it as a string.
+    pub fn recommendation_id(&self) -> Option<u64> {
+        match self {
+            ParsedReview::Typed(review) => Some(review.recommendationid),
+            ParsedReview::Dynamic(map) => map.get("recommendationid").and_then(|value| match value {
+                Value::String(raw) => raw.parse().ok(),
+                Value::Number(num) => num.as_u64(),
+                _ => None,
+            }),
+        }
+    }
+}
+
+// Names FlattenedQuery already pulls up from a Review. Anything outside this set
+// in a Dynamic record is stashed in `FlattenedQuery::extra`.
+const KNOWN_KEYS: &[&str] = &[
+    "recommendationid",
+    "author",
+    "language",
+    "review",
+    "timestamp_created",
+    "timestamp_updated",
+    "voted_up",
+    "votes_up",
+    "votes_funny",
+    "weighted_vote_score",
+    "comment_count",
+    "steam_purchase",
+    "received_for_free",
+    "written_during_early_access",
+    "developer_response",
+    "timestamp_dev_responded",
+];
+
+impl From<ParsedReview> for FlattenedQuery {
+    fn from(parsed: ParsedReview) -> Self {
+        match parsed {
+            ParsedReview::Typed(review) => review.into(),
+            ParsedReview::Dynamic(map) => {
+                // Recover the known columns via a best-effort typed deserialize of
+                // whatever matched, then stash the leftover unknown keys so the
+                // analytics output still captures post-release fields.
+                let mut query: FlattenedQuery =
+                    match serde_json::from_value::<Review>(Value::Object(map.clone())) {
+                        Ok(review) => review.into(),
+                        Err(_) => FlattenedQuery::default(),
+                    };
+
+                for (key, value) in map {
+                    if !KNOWN_KEYS.contains(&key.as_str()) {
+                        query.extra.insert(key, value.to_string());
+                    }
+                }
+                query
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use super::{conv_newtypes::*, reviewscore::ReviewScore};
+use super::{conv_newtypes::*, parsed::ParsedReview, reviewscore::ReviewScore};
 use crate::language::Language;
 use serde::{de::Error, Deserialize, Deserializer};
 
@@ -85,7 +85,7 @@ pub struct Review {
     pub timestamp_dev_responded: Option<UnixTimestamp>,
 }
 
-#[derive(Debug, Deserialize, PartialEq, PartialOrd)]
+#[derive(Debug, Deserialize, PartialEq)]
 pub struct SteamRevOuter {
     /// Did the query succeed? NOTE: Don't rely on this to actually indicate success.
     #[serde(deserialize_with = "success_to_bool")]
@@ -95,8 +95,43 @@ pub struct SteamRevOuter {
     /// The `cursor` references the next page of information. Pass `cursor` into
     /// [ReviewApi::change_cursor] to paginate your current query.
     pub cursor: String,
-    /// Reviews scraped.
-    pub reviews: Vec<Review>,
+    /// Reviews scraped. Each entry is a [`ParsedReview`] so a field Valve added
+    /// after this crate was built lands as [`ParsedReview::Dynamic`] instead of
+    /// failing the whole page's deserialize.
+    pub reviews: Vec<ParsedReview>,
+}
+
+impl Review {
+    /// True if this review's `timestamp_created` falls within the inclusive
+    /// `[start, end]` window.
+    #[inline]
+    pub fn created_within(&self, start: UnixTimestamp, end: UnixTimestamp) -> bool {
+        (start..=end).contains(&self.timestamp_created)
+    }
+
+    /// True if this review's `timestamp_updated` falls within the inclusive
+    /// `[start, end]` window.
+    #[inline]
+    pub fn updated_within(&self, start: UnixTimestamp, end: UnixTimestamp) -> bool {
+        (start..=end).contains(&self.timestamp_updated)
+    }
+}
+
+/// Keep only reviews whose `timestamp_created` falls within `[start, end]`.
+///
+/// Steam's server-side `day_range` only works with certain filters, so this
+/// client-side window lets callers slice an already-scraped set — say "reviews
+/// created in Q1 2021" — without re-querying, which is exactly what an analysis
+/// workflow wants.
+pub fn filter_by_created_window(
+    reviews: Vec<Review>,
+    start: UnixTimestamp,
+    end: UnixTimestamp,
+) -> Vec<Review> {
+    reviews
+        .into_iter()
+        .filter(|review| review.created_within(start, end))
+        .collect()
 }
 
 // Converts Steam ID and recommendation ID from Strings to u64s.
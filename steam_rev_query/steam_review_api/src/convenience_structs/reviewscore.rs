@@ -1,14 +1,21 @@
-use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     fmt::{self, Display, Formatter},
     str::FromStr,
 };
 
 /// Steam review class (i.e. Overwhelmingly Positive) as an enum.
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+///
+/// Valve occasionally adds new score bands, so a strict deserialize would abort a
+/// long scrape the first time an unrecognized description shows up. The
+/// [`ReviewScore::Unknown`] variant keeps the original string instead, the same
+/// "deserialize the primitive, then match known variants and route the rest into
+/// `Unknown`" trick Riven's `serde_strum_unknown` uses.
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[allow(dead_code)]
+#[non_exhaustive]
 pub enum ReviewScore {
-    OverwhelminglyNegative = 1,
+    OverwhelminglyNegative,
     VeryNegative,
     Negative,
     MostlyNegative,
@@ -17,6 +24,9 @@ pub enum ReviewScore {
     Positive,
     VeryPositive,
     OverwhelminglyPositive,
+    /// A description this crate hasn't enumerated yet. The original string is
+    /// preserved so it round-trips back out unchanged.
+    Unknown(String),
 }
 
 // Unit struct for FromStr::Error.
@@ -35,7 +45,7 @@ impl Display for ReviewScoreParseError {
 
 impl ReviewScore {
     /// String representation of the review score.
-    pub fn as_str(self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         use ReviewScore::*;
         match self {
             OverwhelminglyNegative => "Overwhelmingly Negative",
@@ -47,6 +57,7 @@ impl ReviewScore {
             Positive => "Positive",
             VeryPositive => "Very Positive",
             OverwhelminglyPositive => "Overwhelmingly Positive",
+            Unknown(desc) => desc,
         }
     }
 }
@@ -67,11 +78,9 @@ impl FromStr for ReviewScore {
     /// and save memory while doing so.
     ///
     /// ## Errors
-    /// All nine of Steam's review classes are exhaustively covered by ReviewScore.
-    /// Thus, parsing shouldn't cause an error unless:
-    /// * the caller specifically parses a value not covered
-    /// * Steam returns junk data somehow
-    /// * Valve adds new review levels.
+    /// This is the strict path: an unrecognized description returns
+    /// [`ReviewScoreParseError`]. Deserialization routes the unknown string into
+    /// [`ReviewScore::Unknown`] instead so a scrape keeps running.
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         use ReviewScore::*;
         match s {
@@ -94,8 +103,12 @@ impl<'de> Deserialize<'de> for ReviewScore {
     where
         D: Deserializer<'de>,
     {
+        // Deserialize the primitive first, then match known variants and route
+        // anything Valve added since into Unknown rather than erroring.
         let value: String = Deserialize::deserialize(deserializer)?;
-        value.parse::<ReviewScore>().map_err(D::Error::custom)
+        Ok(value
+            .parse::<ReviewScore>()
+            .unwrap_or(ReviewScore::Unknown(value)))
     }
 }
 
@@ -104,6 +117,8 @@ impl Serialize for ReviewScore {
     where
         S: Serializer,
     {
+        // Symmetric with Deserialize: Unknown serializes back as its original
+        // string, so round-tripping preserves the value.
         serializer.serialize_str(self.as_str())
     }
 }
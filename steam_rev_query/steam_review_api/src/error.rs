@@ -4,10 +4,33 @@ use std::{
     result,
 };
 
-#[derive(Debug, Clone, Copy)]
+/// A single problem found while validating a JSON response.
+///
+/// Carries the full JSON pointer path to the offending value (e.g.
+/// `reviews[3].author.num_games_owned`) plus a human message, optionally with a
+/// "did you mean" hint for a misspelt key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    /// JSON pointer path to the value that went wrong.
+    pub path: String,
+    /// What was wrong at that path.
+    pub message: String,
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+#[derive(Debug, Clone)]
 pub enum RevApiError {
     InvalidFilterCursor,
     InvalidFilterDayRange,
+    /// One or more problems found while validating a JSON response. Unlike a raw
+    /// serde error (which fails on the first problem with no location), this lists
+    /// every problem at once, each with its JSON pointer path.
+    InvalidResponse(Vec<FieldError>),
 }
 
 #[allow(dead_code)]
@@ -19,9 +42,20 @@ impl Error for RevApiError {}
 impl Display for RevApiError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         use RevApiError::*;
-        write!(f, "{}", match *self {
-            InvalidFilterCursor => "Cursors (for pagination) are only valid for Filter::Recent or Filter::Updated",
-            InvalidFilterDayRange => "Day ranges are only allowed for Filter::All. You may need to manually call ReviewApi::filter."
-        })
+        match self {
+            InvalidFilterCursor => f.write_str(
+                "Cursors (for pagination) are only valid for Filter::Recent or Filter::Updated",
+            ),
+            InvalidFilterDayRange => f.write_str(
+                "Day ranges are only allowed for Filter::All. You may need to manually call ReviewApi::filter.",
+            ),
+            InvalidResponse(problems) => {
+                writeln!(f, "The response didn't match the expected shape ({} problem(s)):", problems.len())?;
+                for problem in problems {
+                    writeln!(f, "  - {}", problem)?;
+                }
+                Ok(())
+            }
+        }
     }
 }
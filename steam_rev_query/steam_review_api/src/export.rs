@@ -0,0 +1,219 @@
+//! Feature-gated tabular export for scraped reviews.
+//!
+//! [`FlattenedQuery`] already flattens the nested `Review`/`ReviewAuthor` structs
+//! into one row of plain columns, but there was no way to write that out for the
+//! statistical tools this crate exists to feed. This module turns a set of
+//! reviews into an analysis-ready table: one row per review with language,
+//! playtime, votes, early-access flag, and friends.
+//!
+//! CSV and newline-delimited JSON are always available with the `export` feature;
+//! YAML follows rustypipe's `report-yaml` pattern behind an extra `export-yaml`
+//! feature so the YAML dependency stays opt-in.
+
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Display, Formatter},
+    io::Write,
+    path::Path,
+};
+
+use serde_json::{Map, Value};
+
+use crate::{
+    convenience_structs::{flat_query::FlattenedQuery, Review},
+    flatten::flatten,
+};
+
+/// Errors raised while writing an export. Kept local to this module so the base
+/// crate doesn't take on the scraper's larger error enum.
+#[derive(Debug)]
+pub enum ExportError {
+    Io(std::io::Error),
+    Csv(csv::Error),
+}
+
+impl std::error::Error for ExportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ExportError::Io(e) => Some(e),
+            ExportError::Csv(e) => Some(e),
+        }
+    }
+}
+
+impl Display for ExportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ExportError::Io(e) => e.fmt(f),
+            ExportError::Csv(e) => e.fmt(f),
+        }
+    }
+}
+
+impl From<std::io::Error> for ExportError {
+    #[inline]
+    fn from(error: std::io::Error) -> Self {
+        ExportError::Io(error)
+    }
+}
+
+impl From<csv::Error> for ExportError {
+    #[inline]
+    fn from(error: csv::Error) -> Self {
+        ExportError::Csv(error)
+    }
+}
+
+type Result<T> = std::result::Result<T, ExportError>;
+
+// Flatten reviews into rows. The newtype columns (`Minutes`, `UnixTimestamp`)
+// serialize as plain integers via their Serialize impls, so the resulting table
+// is free of wrapper noise and the header order is the stable FlattenedQuery
+// field order.
+fn rows<I>(reviews: I) -> impl Iterator<Item = FlattenedQuery>
+where
+    I: IntoIterator<Item = Review>,
+{
+    reviews.into_iter().map(FlattenedQuery::from)
+}
+
+/// Write `reviews` to `path` as a CSV table, one row per review.
+///
+/// This is the one-call path: the header is emitted once in a stable order and
+/// the author's `steamid`, `num_games_owned`, `num_reviews` and
+/// `playtime_forever` are pulled up to top-level columns. The other author
+/// fields are not exported.
+pub fn write_csv<P, I>(path: P, reviews: I) -> Result<()>
+where
+    P: AsRef<Path>,
+    I: IntoIterator<Item = Review>,
+{
+    let mut writer = csv::Writer::from_path(path)?;
+    for row in rows(reviews) {
+        writer.serialize(row)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Write arbitrary JSON `values` to `path` as a CSV table by recursively
+/// flattening each object into dotted-key columns (see [`crate::flatten`]).
+///
+/// Unlike [`write_csv`], which is fixed to the typed [`FlattenedQuery`] columns,
+/// this path emits whatever keys the payload carries — `query_summary` stats or a
+/// nested field Valve shipped after this crate was built — without a code change.
+/// The header is the sorted union of every row's keys, so the column set stays
+/// stable even when some rows omit a field.
+pub fn write_flattened_csv<P, I>(path: P, values: I) -> Result<()>
+where
+    P: AsRef<Path>,
+    I: IntoIterator<Item = Value>,
+{
+    let (header, rows) = flattened_table(values);
+    let mut writer = csv::Writer::from_path(path)?;
+    writer.write_record(&header)?;
+    for row in &rows {
+        writer.write_record(row)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+// Flatten every value into a row, then square them off against the sorted union
+// of all keys so missing fields land as empty cells. Kept separate from the IO so
+// the column logic can be tested without touching the filesystem.
+fn flattened_table<I>(values: I) -> (Vec<String>, Vec<Vec<String>>)
+where
+    I: IntoIterator<Item = Value>,
+{
+    let maps: Vec<Map<String, Value>> = values.into_iter().map(|value| flatten(&value)).collect();
+    let header: Vec<String> = maps
+        .iter()
+        .flat_map(|map| map.keys().cloned())
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let rows = maps
+        .iter()
+        .map(|map| {
+            header
+                .iter()
+                .map(|key| map.get(key).map_or_else(String::new, value_to_field))
+                .collect()
+        })
+        .collect();
+    (header, rows)
+}
+
+// Render a flattened scalar as a bare CSV field: strings without their JSON
+// quotes, JSON `null` as an empty cell, everything else via its JSON text.
+fn value_to_field(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+/// Write `reviews` to `path` as newline-delimited JSON (one JSON object per line).
+pub fn write_ndjson<P, I>(path: P, reviews: I) -> Result<()>
+where
+    P: AsRef<Path>,
+    I: IntoIterator<Item = Review>,
+{
+    let file = std::fs::File::create(path)?;
+    let mut writer = std::io::BufWriter::new(file);
+    for row in rows(reviews) {
+        // serde_json errors here are serialization bugs, not IO, so they're
+        // unexpected enough to surface rather than swallow.
+        let line = serde_json::to_string(&row)
+            .expect("Unexpected: a FlattenedQuery should always serialize to JSON.");
+        writer.write_all(line.as_bytes())?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Write `reviews` to `path` as a YAML sequence.
+#[cfg(feature = "export-yaml")]
+pub fn write_yaml<P, I>(path: P, reviews: I) -> Result<()>
+where
+    P: AsRef<Path>,
+    I: IntoIterator<Item = Review>,
+{
+    let file = std::fs::File::create(path)?;
+    let rows: Vec<FlattenedQuery> = rows(reviews).collect();
+    serde_yaml::to_writer(file, &rows)
+        .expect("Unexpected: a FlattenedQuery sequence should always serialize to YAML.");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn flattened_table_unions_keys_across_rows() {
+        // Second row carries a field the first lacks; the header is their sorted
+        // union and the missing cell is blank.
+        let values = vec![
+            json!({ "query_summary": { "num_reviews": 2 }, "cursor": "*" }),
+            json!({ "query_summary": { "num_reviews": 0 }, "extra_field": "new" }),
+        ];
+
+        let (header, rows) = flattened_table(values);
+
+        assert_eq!(
+            header,
+            vec![
+                "cursor".to_owned(),
+                "extra_field".to_owned(),
+                "query_summary.num_reviews".to_owned(),
+            ]
+        );
+        assert_eq!(rows[0], vec!["*", "", "2"]);
+        assert_eq!(rows[1], vec!["", "new", "0"]);
+    }
+}
@@ -0,0 +1,87 @@
+//! Generic recursive JSON flattening for arbitrary nested objects.
+//!
+//! [`FlattenedQuery`](crate::convenience_structs::flat_query::FlattenedQuery)
+//! hardcodes one level of `Review`/`ReviewAuthor` columns, so anything else Steam
+//! returns — the top-level `query_summary`, or a nested field added after this
+//! crate was written — can't be emitted as a flat record. This flattener takes a
+//! [`Value`] and recursively produces a single `Map<String, Value>` with dotted
+//! keys, which is enough to dump `query_summary` stats (or any future nested
+//! field) to CSV without a code change.
+//!
+//! The rules:
+//! * nested objects prepend the parent key: `author.steamid`,
+//! * arrays expand by index: `reviews.0`, `reviews.1`, ...,
+//! * empty objects and arrays collapse to a single key with a `null` value so the
+//!   column set stays stable across rows.
+
+use serde_json::{Map, Value};
+
+/// Flatten `value` into a single-level map of dotted keys.
+pub fn flatten(value: &Value) -> Map<String, Value> {
+    let mut out = Map::new();
+    flatten_into("", value, &mut out);
+    out
+}
+
+fn flatten_into(prefix: &str, value: &Value, out: &mut Map<String, Value>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                flatten_into(&join(prefix, key), child, out);
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for (i, child) in items.iter().enumerate() {
+                flatten_into(&join(prefix, &i.to_string()), child, out);
+            }
+        }
+        // Empty object/array collapse to a stable null leaf.
+        Value::Object(_) | Value::Array(_) => {
+            out.insert(leaf(prefix), Value::Null);
+        }
+        // Scalars are leaves.
+        scalar => {
+            out.insert(leaf(prefix), scalar.clone());
+        }
+    }
+}
+
+// Join a prefix and key with a dot, tolerating an empty prefix at the root.
+fn join(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+// A leaf at the very root has no key; give it a stable placeholder rather than an
+// empty string.
+fn leaf(prefix: &str) -> String {
+    if prefix.is_empty() {
+        "value".to_owned()
+    } else {
+        prefix.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn nests_objects_and_arrays() {
+        let value = json!({
+            "query_summary": { "num_reviews": 2, "review_score": 9 },
+            "reviews": [ { "votes_up": 400 }, { "votes_up": 65 } ],
+            "empty": {}
+        });
+
+        let flat = flatten(&value);
+        assert_eq!(flat.get("query_summary.num_reviews"), Some(&json!(2)));
+        assert_eq!(flat.get("reviews.0.votes_up"), Some(&json!(400)));
+        assert_eq!(flat.get("reviews.1.votes_up"), Some(&json!(65)));
+        assert_eq!(flat.get("empty"), Some(&Value::Null));
+    }
+}
@@ -0,0 +1,117 @@
+//! Feature-gated `unic-langid` interop for [`Language`].
+//!
+//! Many Rust i18n stacks — fluent bundles, accept-language guards — already speak
+//! [`unic_langid::LanguageIdentifier`], so a caller pulling Steam reviews next to
+//! a localized frontend can convert a negotiated locale straight into the Steam
+//! query language and back without string juggling. The conversions follow the
+//! existing [`Language::language_code`] table, spelled out per variant rather
+//! than read from it, and handle the `zh-CN`/`zh-TW`, `pt`/`pt-BR`, `es`/`es-419`
+//! distinctions Steam cares about.
+//!
+//! Enable the `unic-langid` feature to pull this in.
+
+use std::convert::TryFrom;
+
+use unic_langid::LanguageIdentifier;
+
+use crate::language::{LangParseError, Language};
+
+impl TryFrom<&LanguageIdentifier> for Language {
+    type Error = LangParseError;
+
+    fn try_from(id: &LanguageIdentifier) -> Result<Self, Self::Error> {
+        use Language::*;
+
+        let language = id.language.as_str();
+        let region = id.region.map(|region| region.as_str().to_owned());
+        let region = region.as_deref();
+
+        let lang = match (language, region) {
+            ("ar", _) => Arabic,
+            ("bg", _) => Bulgarian,
+            ("zh", Some("CN")) => SimplifiedChinese,
+            ("zh", Some("TW")) => TraditionalChinese,
+            ("cs", _) => Czech,
+            ("da", _) => Danish,
+            ("nl", _) => Dutch,
+            ("en", _) => English,
+            ("fi", _) => Finnish,
+            ("fr", _) => French,
+            ("de", _) => German,
+            ("el", _) => Greek,
+            ("hu", _) => Hungarian,
+            ("it", _) => Italian,
+            ("ja", _) => Japanese,
+            ("ko", _) => Korean,
+            ("no", _) => Norwegian,
+            ("pl", _) => Polish,
+            ("pt", Some("BR")) => PortugueseBrazilian,
+            ("pt", _) => Portuguese,
+            ("ro", _) => Romanian,
+            ("ru", _) => Russian,
+            ("es", Some("419")) => SpanishLatAm,
+            ("es", _) => SpanishSpain,
+            ("sv", _) => Swedish,
+            ("th", _) => Thai,
+            ("tr", _) => Turkish,
+            ("uk", _) => Ukrainian,
+            ("vi", _) => Vietnamese,
+            _ => return Err(LangParseError),
+        };
+        Ok(lang)
+    }
+}
+
+impl TryFrom<LanguageIdentifier> for Language {
+    type Error = LangParseError;
+
+    #[inline]
+    fn try_from(id: LanguageIdentifier) -> Result<Self, Self::Error> {
+        Language::try_from(&id)
+    }
+}
+
+impl From<Language> for LanguageIdentifier {
+    fn from(lang: Language) -> Self {
+        // A clean BCP-47 tag per variant. Steam's `language_code` table has a
+        // couple of quirks (`"el el"`, `"vn"`) so the interop tags are spelled out
+        // rather than parsed straight from it.
+        use Language::*;
+        let tag = match &lang {
+            Arabic => "ar",
+            Bulgarian => "bg",
+            SimplifiedChinese => "zh-CN",
+            TraditionalChinese => "zh-TW",
+            Czech => "cs",
+            Danish => "da",
+            Dutch => "nl",
+            English => "en",
+            Finnish => "fi",
+            French => "fr",
+            German => "de",
+            Greek => "el",
+            Hungarian => "hu",
+            Italian => "it",
+            Japanese => "ja",
+            Korean => "ko",
+            Norwegian => "no",
+            Polish => "pl",
+            Portuguese => "pt",
+            PortugueseBrazilian => "pt-BR",
+            Romanian => "ro",
+            Russian => "ru",
+            SpanishSpain => "es",
+            SpanishLatAm => "es-419",
+            Swedish => "sv",
+            Thai => "th",
+            Turkish => "tr",
+            Ukrainian => "uk",
+            Vietnamese => "vi",
+            // `All` isn't a locale, and an Unknown tag may or may not be valid
+            // BCP-47. Try the stored tag, otherwise fall back to undetermined.
+            All => "und",
+            Unknown(tag) => tag,
+        };
+        tag.parse().unwrap_or_else(|_| LanguageIdentifier::default())
+    }
+}
@@ -1,5 +1,5 @@
 #[cfg(feature = "convenience_structs")]
-use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     fmt::{self, Display, Formatter},
     str::FromStr,
@@ -8,7 +8,7 @@ use std::{
 /// Languages as represented by the Steam API.
 /// Source: https://partner.steamgames.com/doc/store/localization
 #[allow(dead_code)]
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 #[non_exhaustive]
 pub enum Language {
     All,
@@ -41,6 +41,17 @@ pub enum Language {
     Turkish,
     Ukrainian,
     Vietnamese,
+    /// A language tag Valve shipped that this crate hasn't enumerated yet. The
+    /// original string is preserved so it round-trips unchanged instead of
+    /// blowing up a batch mid-scrape.
+    Unknown(Box<str>),
+}
+
+// The language subtag of a BCP-47 tag (or a Steam language code), i.e. everything
+// before the first region delimiter. Splitting on whitespace too tidies up the
+// one quirky code (`"el el"`).
+fn base_subtag(tag: &str) -> &str {
+    tag.split(['-', ' ']).next().unwrap_or(tag)
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -60,7 +71,7 @@ impl Display for LangParseError {
 
 impl Language {
     /// String representation of how Language appears in queries.
-    pub fn as_str(self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         use Language::*;
         match self {
             All => "all",
@@ -93,11 +104,12 @@ impl Language {
             Turkish => "turkish",
             Ukrainian => "ukrainian",
             Vietnamese => "vietnamese",
+            Unknown(tag) => tag,
         }
     }
 
     /// Shorthand language code as represented by the Steam web API.
-    pub fn language_code(self) -> &'static str {
+    pub fn language_code(&self) -> &str {
         use Language::*;
         match self {
             All => "all",
@@ -130,11 +142,145 @@ impl Language {
             Turkish => "tr",
             Ukrainian => "uk",
             Vietnamese => "vn",
+            // No canonical code for an unknown tag; echo the original back.
+            Unknown(tag) => tag,
+        }
+    }
+
+    // Every concrete language variant (excluding `All`). Used by negotiation to
+    // walk the known language codes.
+    const VARIANTS: [Language; 29] = {
+        use Language::*;
+        [
+            Arabic,
+            Bulgarian,
+            SimplifiedChinese,
+            TraditionalChinese,
+            Czech,
+            Danish,
+            Dutch,
+            English,
+            Finnish,
+            French,
+            German,
+            Greek,
+            Hungarian,
+            Italian,
+            Japanese,
+            Korean,
+            Norwegian,
+            Polish,
+            Portuguese,
+            PortugueseBrazilian,
+            Romanian,
+            Russian,
+            SpanishSpain,
+            SpanishLatAm,
+            Swedish,
+            Thai,
+            Turkish,
+            Ukrainian,
+            Vietnamese,
+        ]
+    };
+
+    /// Every concrete [`Language`] Steam stores, in declaration order.
+    ///
+    /// Excludes the `All` sentinel and the `Unknown` fallthrough — it's the set a
+    /// caller would rotate through to pull a full cross-locale corpus, the way
+    /// `strum::EnumIter` is used in comparable enum-heavy crates.
+    #[inline]
+    pub fn all() -> impl Iterator<Item = Language> {
+        Self::VARIANTS.into_iter()
+    }
+
+    /// Alias for [`Language::all`].
+    #[inline]
+    pub fn iter() -> impl Iterator<Item = Language> {
+        Self::all()
+    }
+
+    /// Best-matching [`Language`] for a prioritized list of BCP-47 locale tags.
+    ///
+    /// Tags are tried in order. Each is first matched case-insensitively against
+    /// every variant's [`language_code`](Language::language_code) (so `pt-BR` ->
+    /// `PortugueseBrazilian`, `es-419` -> `SpanishLatAm`); failing that, against
+    /// the language subtag alone, ignoring the region (`pt` -> `Portuguese`, `es`
+    /// -> `SpanishSpain` as the canonical base). The first tag that resolves wins;
+    /// if nothing resolves, [`Language::English`] is returned.
+    pub fn negotiate(preferred: &[&str]) -> Language {
+        // Resolve each tag fully before moving on: try an exact code match, then
+        // fall back to the language subtag, and take the first tag that lands.
+        for tag in preferred {
+            if let Some(lang) = Self::from_code_exact(tag).or_else(|| Self::from_code_base(tag)) {
+                return lang;
+            }
         }
+        Language::English
+    }
+
+    /// Negotiate a [`Language`] from an HTTP `Accept-Language` header.
+    ///
+    /// The header is parsed into `(tag, q)` pairs (split on `,`, each on `;q=`,
+    /// quality defaulting to `1.0`, malformed quality skipped), sorted descending
+    /// by quality with ties kept in source order, then resolved via
+    /// [`negotiate`](Language::negotiate).
+    pub fn from_accept_language(header: &str) -> Language {
+        let mut tags: Vec<(&str, f32)> = header
+            .split(',')
+            .filter_map(|entry| {
+                let mut parts = entry.split(";q=");
+                let tag = parts.next()?.trim();
+                if tag.is_empty() {
+                    return None;
+                }
+                // No quality means 1.0; a present-but-unparsable quality is
+                // malformed and the whole entry is skipped.
+                let quality = match parts.next() {
+                    None => 1.0,
+                    Some(q) => q.trim().parse::<f32>().ok()?,
+                };
+                Some((tag, quality))
+            })
+            .collect();
+
+        // Stable sort keeps ties in source order.
+        tags.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let ordered: Vec<&str> = tags.into_iter().map(|(tag, _)| tag).collect();
+        Language::negotiate(&ordered)
+    }
+
+    // Case-insensitive exact match against each variant's language code.
+    fn from_code_exact(tag: &str) -> Option<Language> {
+        Self::VARIANTS
+            .iter()
+            .find(|lang| lang.language_code().eq_ignore_ascii_case(tag))
+            .cloned()
+    }
+
+    // Match on the language subtag alone, ignoring the region. The canonical base
+    // (a variant whose full code is just the base, like `es`) is preferred.
+    fn from_code_base(tag: &str) -> Option<Language> {
+        let tag_base = base_subtag(tag);
+        let mut fallback = None;
+        for lang in Self::VARIANTS {
+            let code = lang.language_code();
+            if base_subtag(code).eq_ignore_ascii_case(tag_base) {
+                if code.eq_ignore_ascii_case(tag_base) {
+                    return Some(lang);
+                }
+                fallback.get_or_insert(lang);
+            }
+        }
+        fallback
     }
 
     /// Language's native name.
-    pub fn native_name(self) -> &'static str {
+    pub fn native_name(&self) -> &str {
         use Language::*;
         match self {
             All => "All",
@@ -167,6 +313,7 @@ impl Language {
             Turkish => "Türkçe",
             Ukrainian => "Українська",
             Vietnamese => "Tiếng Việt",
+            Unknown(tag) => tag,
         }
     }
 }
@@ -177,17 +324,12 @@ impl Display for Language {
     }
 }
 
-impl FromStr for Language {
-    type Err = LangParseError;
-
-    /// String slice to Language.
-    /// Native names as well as shorthands are handled.
-    ///
-    /// ## Errors
-    /// Returns [LangParseError] if an unsupported language is passed in.
-    /// In other words, this function shouldn't fail until Valve adds in new
-    /// languages...in which case you should let me know!
-    fn from_str(s: &str) -> Result<Self, LangParseError> {
+impl Language {
+    /// Strict string slice to [`Language`]. Native names and shorthands are
+    /// handled, but an unlisted language is an [`LangParseError`] rather than an
+    /// [`Language::Unknown`]. Use this when you specifically want to know Valve
+    /// shipped something new; the [`FromStr`]/[`Deserialize`] paths are lenient.
+    pub fn from_str_strict(s: &str) -> Result<Self, LangParseError> {
         use Language::*;
         match s {
             "all" => Ok(All),
@@ -225,6 +367,19 @@ impl FromStr for Language {
     }
 }
 
+impl FromStr for Language {
+    // Infallible in practice: unrecognized tags fall through to
+    // [`Language::Unknown`] rather than erroring. The associated error type is
+    // kept so [`Language::from_str_strict`] and this share the same surface.
+    type Err = LangParseError;
+
+    /// String slice to [`Language`], routing anything unrecognized into
+    /// [`Language::Unknown`] so a scrape keeps running when Valve adds a language.
+    fn from_str(s: &str) -> Result<Self, LangParseError> {
+        Ok(Language::from_str_strict(s).unwrap_or_else(|_| Language::Unknown(s.into())))
+    }
+}
+
 // Deserialize and Serialize
 #[cfg(feature = "convenience_structs")]
 impl<'de> Deserialize<'de> for Language {
@@ -232,8 +387,10 @@ impl<'de> Deserialize<'de> for Language {
     where
         D: Deserializer<'de>,
     {
+        // Lenient: an unrecognized tag becomes Language::Unknown instead of
+        // failing the whole response parse.
         let s: String = Deserialize::deserialize(deserializer)?;
-        s.parse::<Language>().map_err(D::Error::custom)
+        Ok(Language::from_str(&s).unwrap_or_else(|_| Language::Unknown(s.into())))
     }
 }
 
@@ -263,8 +420,31 @@ mod tests {
     }
 
     #[test]
-    fn bad_parse() {
+    fn unknown_parse_is_captured() {
+        // Deserialize no longer fails on an unlisted tag; it lands in Unknown and
+        // round-trips the original string back out.
         let cat_lang: StringDeserializer<Error> = "meow talk".to_owned().into_deserializer();
-        let _err = Language::deserialize(cat_lang).unwrap_err();
+        let parsed = Language::deserialize(cat_lang).expect("Unknown tags should not error.");
+        assert_eq!(parsed, Language::Unknown("meow talk".into()));
+        assert_eq!(parsed.as_str(), "meow talk");
+    }
+
+    #[test]
+    fn strict_parse_still_errors() {
+        Language::from_str_strict("meow talk").unwrap_err();
+    }
+
+    #[test]
+    fn negotiate_resolves_each_tag_in_order() {
+        // The first tag resolves (via its base `de`), so it wins even though a
+        // later tag has an exact match.
+        assert_eq!(
+            Language::negotiate(&["de-CH", "pt-BR"]),
+            Language::German
+        );
+        // Exact match is still preferred over the base within a single tag.
+        assert_eq!(Language::negotiate(&["pt-BR"]), Language::PortugueseBrazilian);
+        // Nothing resolves -> English.
+        assert_eq!(Language::negotiate(&["xx-YY"]), Language::English);
     }
 }
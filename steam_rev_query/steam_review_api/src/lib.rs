@@ -20,8 +20,43 @@ mod options;
 #[cfg(feature = "convenience_structs")]
 pub mod convenience_structs;
 
+// Async auto-pagination client. Pulls in reqwest + async-stream so it's gated.
+#[cfg(all(feature = "reqwest_stream", feature = "convenience_structs"))]
+mod stream;
+
+// Tabular export (CSV/NDJSON/YAML) for downstream statistical tools.
+#[cfg(all(feature = "export", feature = "convenience_structs"))]
+pub mod export;
+
+// Pluggable HTTP execution layer with pacing and 429 retries.
+#[cfg(all(feature = "client", feature = "convenience_structs"))]
+pub mod client;
+
+// Cursor auto-pagination built on the execution layer.
+#[cfg(all(feature = "client", feature = "convenience_structs"))]
+pub mod paginate;
+
+// Concurrent multi-appid pagination over the execution layer.
+#[cfg(all(feature = "client", feature = "convenience_structs"))]
+pub mod multi;
+
+// Path-aware "did you mean" response validation.
+#[cfg(feature = "convenience_structs")]
+pub mod validate;
+
+// Generic recursive JSON flattening for arbitrary nested objects.
+#[cfg(feature = "convenience_structs")]
+pub mod flatten;
+
+// Optional conversions to/from unic-langid's LanguageIdentifier.
+#[cfg(feature = "unic-langid")]
+mod langid;
+
 // Re-export the API builder, error enum, and options enums to ease importing.
 pub use buildapi::ReviewApi;
-pub use error::RevApiError;
+pub use error::{FieldError, RevApiError};
 pub use language::Language;
-pub use options::{Filter, PurchaseType, ReviewType};
+pub use options::{DayRange, Filter, PurchaseType, ReviewType};
+
+#[cfg(all(feature = "reqwest_stream", feature = "convenience_structs"))]
+pub use stream::StreamError;
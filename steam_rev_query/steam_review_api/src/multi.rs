@@ -0,0 +1,70 @@
+//! Concurrent multi-appid auto-pagination.
+//!
+//! [`ReviewClient::paginate`] drives one appid at a time, which is fine for a
+//! single game but serial across a batch: a slow appid with tens of thousands of
+//! reviews holds up every other appid behind it. This adaptor pages several
+//! appids at once, each with its own [`ReviewClient`] (and therefore its own
+//! cursor and rate limiter), merging their pages into one stream tagged by appid.
+//!
+//! At most `concurrency` appids (the caller's choice; [`DEFAULT_CONCURRENCY`] is
+//! the default to pass) are in flight at a time; their pages arrive interleaved
+//! via unordered buffering, so the fastest appids don't wait on the slowest. A
+//! failure on one appid ends only that appid's stream — the rest keep going — so
+//! callers can fold the tagged results into a per-appid map (such as the
+//! scraper's `ResumeInfo`) and checkpoint each game independently.
+
+use std::pin::Pin;
+
+use futures_core::Stream;
+use futures_util::StreamExt;
+use url::Url;
+
+use crate::{
+    client::{ClientError, ReviewClient},
+    convenience_structs::flat_query::{FlattenedQuery, TitleSerde},
+    paginate::PageCap,
+};
+
+/// Default number of appids paged concurrently.
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+/// One appid's slice of a multi-appid run.
+///
+/// Each job owns a dedicated [`ReviewClient`] so the appids page in parallel
+/// without sharing a single rate limiter. Build the `url` with
+/// [`ReviewApi::build`](crate::ReviewApi::build) as usual.
+pub struct MultiAppidJob {
+    pub client: ReviewClient,
+    pub url: Url,
+    pub title: TitleSerde,
+    pub appid: TitleSerde,
+    pub cap: PageCap,
+}
+
+/// Each yielded review, tagged with the appid it was scraped from.
+type TaggedItem = (TitleSerde, Result<FlattenedQuery, ClientError>);
+
+/// Auto-paginate every job in `jobs` at once, yielding each review tagged with
+/// its appid.
+///
+/// No more than `concurrency` appids are polled at a time (clamped to at least
+/// one). Each appid advances its own cursor independently, and an error on one
+/// appid terminates only that appid's stream.
+pub fn paginate_many(
+    jobs: Vec<MultiAppidJob>,
+    concurrency: usize,
+) -> impl Stream<Item = TaggedItem> {
+    let limit = concurrency.max(1);
+    let streams = jobs.into_iter().map(|job| {
+        let appid = job.appid.clone();
+        // Box the generator streams so the merged stream is `Unpin`, as
+        // `flatten_unordered` requires of its inner streams.
+        let tagged = job
+            .client
+            .paginate(job.url, job.title, job.appid, job.cap)
+            .map(move |item| (appid.clone(), item));
+        Box::pin(tagged) as Pin<Box<dyn Stream<Item = TaggedItem>>>
+    });
+
+    futures_util::stream::iter(streams).flatten_unordered(limit)
+}
@@ -79,3 +79,45 @@ impl Default for PurchaseType {
         PurchaseType::Steam
     }
 }
+
+/// A bounded span of days for `Filter::All` `day_range` queries.
+///
+/// Steam's API only accepts a single `day_range` integer (the number of days to
+/// look back from today, capped at a year), but a raw integer is an awkward thing
+/// to hand a caller. `DayRange` follows the same idea as Stripe's `RangeQuery`
+/// over a `Timestamp`: the caller describes a span and the builder resolves it to
+/// the single lookback integer Steam actually wants.
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DayRange {
+    /// Reviews from the last `n` days up to now.
+    LastDays(u32),
+    /// A `[from, to]` window, both expressed as days-ago, in either order. Steam
+    /// takes a single lookback, so only the older bound is sent (see
+    /// [`DayRange::days`]); reviews more recent than the newer bound are
+    /// returned as well.
+    Span { from: u32, to: u32 },
+}
+
+impl DayRange {
+    /// Steam counts a year as the maximum lookback.
+    pub const MAX_DAYS: u32 = 365;
+
+    /// Resolve the span into the single lookback integer Steam expects, clamped
+    /// to [`DayRange::MAX_DAYS`]. The older bound wins because Steam walks
+    /// backwards from today.
+    pub fn days(self) -> u32 {
+        let days = match self {
+            DayRange::LastDays(n) => n,
+            DayRange::Span { from, to } => from.max(to),
+        };
+        days.min(Self::MAX_DAYS)
+    }
+}
+
+impl From<u32> for DayRange {
+    #[inline]
+    fn from(days: u32) -> Self {
+        DayRange::LastDays(days)
+    }
+}
@@ -0,0 +1,189 @@
+//! Feature-gated cursor auto-pagination yielding fully-populated rows.
+//!
+//! The builder already threads the `cursor` query param and forces
+//! `Filter::Recent`/`Updated` for paging, but consumers otherwise have to read
+//! the returned cursor, call [`ReviewApi::change_cursor`], and rebuild each page
+//! by hand. This adaptor drives that loop: it issues the first request, reads the
+//! `cursor` from the body, feeds it back in, and keeps going until the cursor
+//! stops changing or a page comes back empty.
+//!
+//! Unlike [`crate::stream`], which yields raw `ParsedReview`s, this one carries the
+//! scraped `title`/`appid` through [`FlattenedQuery::from_with_titles`] so the
+//! downstream records are fully populated, and it offers both a [`Stream`] and a
+//! blocking [`Iterator`].
+
+use async_stream::try_stream;
+use futures_core::Stream;
+
+use crate::{
+    client::{ClientError, ReviewClient},
+    convenience_structs::flat_query::{FlattenedQuery, TitleSerde},
+};
+use url::Url;
+
+/// Caps on how much a paginated run will pull before stopping on its own.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PageCap {
+    /// Stop after yielding this many reviews, if set.
+    pub max_reviews: Option<usize>,
+    /// Stop after fetching this many pages, if set.
+    pub max_pages: Option<usize>,
+}
+
+impl ReviewClient {
+    /// Auto-paginate `url`, yielding each review as a [`FlattenedQuery`] tagged
+    /// with `title`/`appid`.
+    ///
+    /// Stops when Steam repeats the cursor, returns an empty page, or either cap
+    /// in `cap` is reached.
+    pub fn paginate(
+        mut self,
+        url: Url,
+        title: TitleSerde,
+        appid: TitleSerde,
+        cap: PageCap,
+    ) -> impl Stream<Item = Result<FlattenedQuery, ClientError>> {
+        try_stream! {
+            let mut cursor = String::from("*");
+            let mut pages = 0usize;
+            let mut emitted = 0usize;
+
+            loop {
+                if matches!(cap.max_pages, Some(max) if pages >= max) {
+                    break;
+                }
+
+                let page = with_cursor(&url, &cursor);
+                let outer = self.fetch(page).await?;
+
+                if outer.reviews.is_empty() {
+                    break;
+                }
+                pages += 1;
+
+                for review in outer.reviews {
+                    yield FlattenedQuery::from_with_titles(review, title.clone(), appid.clone());
+                    emitted += 1;
+                    if matches!(cap.max_reviews, Some(max) if emitted >= max) {
+                        return;
+                    }
+                }
+
+                if outer.cursor == cursor {
+                    break;
+                }
+                cursor = outer.cursor;
+            }
+        }
+    }
+}
+
+impl ReviewClient {
+    /// Fan out across `language_urls` (from
+    /// [`ReviewApi::build_languages`](crate::ReviewApi::build_languages)),
+    /// auto-paginating each and merging the results into one stream.
+    ///
+    /// Each yielded [`FlattenedQuery`] already carries its own per-review
+    /// language; the pages are pulled one language at a time so the client's
+    /// single rate limiter paces the whole corpus pull. The caps in `cap` count
+    /// across all languages together.
+    pub fn paginate_languages(
+        mut self,
+        language_urls: Vec<(crate::Language, Url)>,
+        title: TitleSerde,
+        appid: TitleSerde,
+        cap: PageCap,
+    ) -> impl Stream<Item = Result<FlattenedQuery, ClientError>> {
+        try_stream! {
+            let mut pages = 0usize;
+            let mut emitted = 0usize;
+            for (_language, url) in language_urls {
+                let mut cursor = String::from("*");
+
+                loop {
+                    if matches!(cap.max_pages, Some(max) if pages >= max) {
+                        break;
+                    }
+
+                    let page = with_cursor(&url, &cursor);
+                    let outer = self.fetch(page).await?;
+
+                    if outer.reviews.is_empty() {
+                        break;
+                    }
+                    pages += 1;
+
+                    for review in outer.reviews {
+                        yield FlattenedQuery::from_with_titles(
+                            review,
+                            title.clone(),
+                            appid.clone(),
+                        );
+                        emitted += 1;
+                        if matches!(cap.max_reviews, Some(max) if emitted >= max) {
+                            return;
+                        }
+                    }
+
+                    if outer.cursor == cursor {
+                        break;
+                    }
+                    cursor = outer.cursor;
+                }
+            }
+        }
+    }
+}
+
+// Replace the `cursor` query pair with `cursor`, letting the url crate re-encode
+// everything else.
+fn with_cursor(base: &Url, cursor: &str) -> Url {
+    let mut next = base.clone();
+    let pairs: Vec<(String, String)> = base
+        .query_pairs()
+        .filter(|(key, _)| key != "cursor")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+    next.query_pairs_mut()
+        .clear()
+        .extend_pairs(pairs)
+        .append_pair("cursor", cursor);
+    next
+}
+
+/// Blocking [`Iterator`] wrapper over [`ReviewClient::paginate`].
+///
+/// Each `next()` drives the underlying async stream to the next item on a small
+/// current-thread runtime, for callers who don't want an async context.
+pub struct BlockingPaginator {
+    runtime: tokio::runtime::Runtime,
+    stream: std::pin::Pin<Box<dyn Stream<Item = Result<FlattenedQuery, ClientError>>>>,
+}
+
+impl BlockingPaginator {
+    pub fn new(
+        client: ReviewClient,
+        url: Url,
+        title: TitleSerde,
+        appid: TitleSerde,
+        cap: PageCap,
+    ) -> std::io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        Ok(Self {
+            runtime,
+            stream: Box::pin(client.paginate(url, title, appid, cap)),
+        })
+    }
+}
+
+impl Iterator for BlockingPaginator {
+    type Item = Result<FlattenedQuery, ClientError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use futures_util::StreamExt;
+        let stream = &mut self.stream;
+        self.runtime.block_on(async { stream.next().await })
+    }
+}
@@ -0,0 +1,141 @@
+//! Feature-gated async auto-pagination for the review API.
+//!
+//! [`ReviewApi::build`] only ever produces a [`Url`], so a caller who wants every
+//! review would otherwise loop on [`SteamRevOuter::cursor`] by hand and re-feed it
+//! into the builder. This module adds a thin `reqwest` client that drives the
+//! cursor automatically and hands back each [`ParsedReview`] as a
+//! [`Stream`], the way rspotify and Stripe's `List` expose auto-paginated results.
+//!
+//! Enable the `reqwest_stream` feature to pull this in.
+
+use std::collections::HashSet;
+
+use async_stream::try_stream;
+use futures_core::Stream;
+use reqwest::Client;
+use url::Url;
+
+use crate::{buildapi::ReviewApi, convenience_structs::ParsedReview};
+
+/// Errors surfaced by [`ReviewApi::stream`].
+///
+/// Network and deserialize failures become stream items rather than panics so a
+/// long scrape can decide whether to bail or keep going.
+#[derive(Debug)]
+pub enum StreamError {
+    /// The request itself failed or returned a non-success status.
+    Request(reqwest::Error),
+    /// The builder produced a URL that couldn't be parsed. Shouldn't happen.
+    Build(url::ParseError),
+}
+
+impl std::error::Error for StreamError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StreamError::Request(e) => Some(e),
+            StreamError::Build(e) => Some(e),
+        }
+    }
+}
+
+impl std::fmt::Display for StreamError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Request(e) => e.fmt(f),
+            StreamError::Build(e) => e.fmt(f),
+        }
+    }
+}
+
+impl From<reqwest::Error> for StreamError {
+    #[inline]
+    fn from(error: reqwest::Error) -> Self {
+        StreamError::Request(error)
+    }
+}
+
+impl From<url::ParseError> for StreamError {
+    #[inline]
+    fn from(error: url::ParseError) -> Self {
+        StreamError::Build(error)
+    }
+}
+
+// Rebuild `base` with its `cursor` query pair replaced by `cursor`. Steam hands
+// back the raw cursor, and `append_pair` URL-encodes it while the url crate
+// re-encodes the rest of the pairs.
+fn with_cursor(base: &Url, cursor: &str) -> Url {
+    let mut next = base.clone();
+    let pairs: Vec<(String, String)> = base
+        .query_pairs()
+        .filter(|(key, _)| key != "cursor")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+    next.query_pairs_mut()
+        .clear()
+        .extend_pairs(pairs)
+        .append_pair("cursor", cursor);
+    next
+}
+
+impl ReviewApi<'_> {
+    /// Stream every [`ParsedReview`] for this query, driving the cursor
+    /// automatically.
+    ///
+    /// The stream issues the first request, yields each review from
+    /// [`SteamRevOuter::reviews`](crate::convenience_structs::SteamRevOuter), then
+    /// URL-encodes the returned cursor into the next request and repeats. It stops
+    /// when Steam returns an empty `reviews` vec or repeats the same cursor — the
+    /// two natural end-of-pagination signals.
+    ///
+    /// Records that don't match the known schema are kept as
+    /// [`ParsedReview::Dynamic`] rather than failing the page. Reviews are
+    /// de-duplicated by `recommendationid` across pages because Steam occasionally
+    /// repeats entries at page boundaries; a record with no recoverable id is
+    /// always yielded. HTTP and deserialize failures are surfaced as
+    /// [`StreamError`] items instead of panicking.
+    pub fn stream(
+        &self,
+        client: Client,
+    ) -> Result<impl Stream<Item = Result<ParsedReview, StreamError>>, StreamError> {
+        use crate::convenience_structs::SteamRevOuter;
+
+        let base = self.build()?;
+
+        Ok(try_stream! {
+            let mut cursor = String::from("*");
+            let mut seen: HashSet<u64> = HashSet::new();
+
+            loop {
+                let url = with_cursor(&base, &cursor);
+                let outer: SteamRevOuter = client
+                    .get(url)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+
+                // An empty page means Steam has nothing left for us.
+                if outer.reviews.is_empty() {
+                    break;
+                }
+
+                for review in outer.reviews {
+                    match review.recommendation_id() {
+                        // Dedup by id when we have one; otherwise pass it through so
+                        // an unrecognized record is never silently dropped.
+                        Some(id) if !seen.insert(id) => continue,
+                        _ => yield review,
+                    }
+                }
+
+                // A repeated cursor is Steam's other "that's all" signal.
+                if outer.cursor == cursor {
+                    break;
+                }
+                cursor = outer.cursor;
+            }
+        })
+    }
+}
@@ -0,0 +1,158 @@
+//! Path-aware, "did-you-mean" response validation.
+//!
+//! Stock `serde` fails on the first problem with an opaque message and no JSON
+//! location, which is miserable when scraping thousands of reviews where one
+//! malformed record shouldn't abort the batch. Borrowing deserr's two-phase idea,
+//! this module parses the body into a [`serde_json::Value`] first, then walks it
+//! against the known field set and accumulates [`FieldError`]s that each carry a
+//! JSON pointer path. Unknown keys get a Levenshtein-based "did you mean ...?"
+//! hint when the closest known field is within an edit distance of two.
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::{
+    convenience_structs::SteamRevOuter,
+    error::{FieldError, RevApiError},
+};
+
+// Known keys for each object we validate. These mirror the serde structs.
+const OUTER_FIELDS: &[&str] = &["success", "query_summary", "cursor", "reviews"];
+const REVIEW_FIELDS: &[&str] = &[
+    "recommendationid",
+    "author",
+    "language",
+    "review",
+    "timestamp_created",
+    "timestamp_updated",
+    "voted_up",
+    "votes_up",
+    "votes_funny",
+    "weighted_vote_score",
+    "comment_count",
+    "steam_purchase",
+    "received_for_free",
+    "written_during_early_access",
+    "developer_response",
+    "timestamp_dev_responded",
+];
+const AUTHOR_FIELDS: &[&str] = &[
+    "steamid",
+    "num_games_owned",
+    "num_reviews",
+    "playtime_forever",
+    "playtime_last_two_weeks",
+    "playtime_at_review",
+    "last_played",
+];
+
+// Levenshtein edit distance between two strings. Small and self-contained so we
+// don't drag in a crate for one "did you mean" hint.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut curr = vec![0usize; b_chars.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, &b_char) in b_chars.iter().enumerate() {
+            let cost = if a_char == b_char { 0 } else { 1 };
+            curr[j + 1] = (prev[j + 1] + 1).min(curr[j] + 1).min(prev[j] + cost);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b_chars.len()]
+}
+
+// Closest known field to `key` within edit distance 2, if any.
+fn did_you_mean(key: &str, known: &[&str]) -> Option<&'static str> {
+    known
+        .iter()
+        .map(|&field| (field, levenshtein(key, field)))
+        .filter(|&(_, dist)| dist <= 2)
+        .min_by_key(|&(_, dist)| dist)
+        .map(|(field, _)| field)
+}
+
+// Check every key in `object` against `known`, pushing a FieldError for each
+// unrecognized key with a "did you mean" hint when one is close.
+fn check_keys(object: &serde_json::Map<String, Value>, known: &[&str], path: &str, out: &mut Vec<FieldError>) {
+    for key in object.keys() {
+        if !known.contains(&key.as_str()) {
+            let message = match did_you_mean(key, known) {
+                Some(suggestion) => format!("unknown field `{}`; did you mean `{}`?", key, suggestion),
+                None => format!("unknown field `{}`", key),
+            };
+            out.push(FieldError {
+                path: if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) },
+                message,
+            });
+        }
+    }
+}
+
+// Walk a whole response Value, collecting problems across the outer object, every
+// review, and every review's author.
+fn collect_problems(value: &Value) -> Vec<FieldError> {
+    let mut problems = Vec::new();
+
+    let Some(outer) = value.as_object() else {
+        problems.push(FieldError {
+            path: String::new(),
+            message: "expected a JSON object at the top level".to_owned(),
+        });
+        return problems;
+    };
+
+    check_keys(outer, OUTER_FIELDS, "", &mut problems);
+
+    if let Some(Value::Array(reviews)) = outer.get("reviews") {
+        for (i, review) in reviews.iter().enumerate() {
+            let path = format!("reviews[{}]", i);
+            if let Some(review) = review.as_object() {
+                check_keys(review, REVIEW_FIELDS, &path, &mut problems);
+                if let Some(author) = review.get("author").and_then(Value::as_object) {
+                    check_keys(author, AUTHOR_FIELDS, &format!("{}.author", path), &mut problems);
+                }
+            } else {
+                problems.push(FieldError {
+                    path,
+                    message: "expected a review object".to_owned(),
+                });
+            }
+        }
+    }
+
+    problems
+}
+
+/// Validate `value` against the known response shape, then deserialize it.
+///
+/// On a shape mismatch this returns [`RevApiError::InvalidResponse`] listing every
+/// problem with its JSON pointer path, rather than the first raw serde error.
+pub fn try_from_json(value: Value) -> Result<SteamRevOuter, RevApiError> {
+    from_value(value)
+}
+
+/// Two-phase deserialize: validate the key set, accumulating problems, then hand
+/// the `Value` to serde. The key check always uses the [`SteamRevOuter`]
+/// response shape; `T` only picks the type serde deserializes into.
+pub fn from_value<T>(value: Value) -> Result<T, RevApiError>
+where
+    T: DeserializeOwned,
+{
+    let problems = collect_problems(&value);
+    if !problems.is_empty() {
+        return Err(RevApiError::InvalidResponse(problems));
+    }
+
+    // Shape looks right; let serde do the typed conversion. A residual serde
+    // error becomes a single problem with an empty path so callers get one
+    // uniform error type back.
+    serde_json::from_value(value).map_err(|e| {
+        RevApiError::InvalidResponse(vec![FieldError {
+            path: String::new(),
+            message: e.to_string(),
+        }])
+    })
+}
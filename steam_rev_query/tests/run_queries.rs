@@ -1,5 +1,5 @@
-use review_scraper::ReviewScraper;
-use std::convert::TryInto;
+use review_scraper::{FixtureTransport, ReviewScraper};
+use std::{convert::TryInto, path::PathBuf};
 use steam_review_api::ReviewApi;
 
 // Several App IDs that are useful for testing. The list below may be overkill.
@@ -24,9 +24,16 @@ const COMM_MOD: u32 = 365720;
 // Used to complete a full scrape.
 const LOW_REVS: u32 = 9160;
 
+// Saved Steam responses, keyed by appid + cursor. Recorded once via
+// `FixtureTransport::record`; see the `live_*` tests below for refreshing them.
+fn fixtures() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+// Pull a single page for `appid` from the fixtures, offline and deterministic.
 fn test_base(appid: u32) {
-    let mut query: ReviewScraper = ReviewApi::new(appid)
-        .try_into()
+    let transport = Box::new(FixtureTransport::replay(fixtures()));
+    let mut query = ReviewScraper::with_transport(ReviewApi::new(appid), transport)
         .expect("Building basic query failed.");
     let _response = query
         .next()
@@ -73,6 +80,35 @@ fn test_comm_mod() {
 // if the scraper behaves well over several queries.
 #[test]
 fn full_scrape() {
+    let transport = Box::new(FixtureTransport::replay(fixtures()));
+    let mut api = ReviewApi::new(LOW_REVS);
+    api.num_per_page(100);
+    let scraper =
+        ReviewScraper::with_transport(api, transport).expect("Building full_scrape query failed");
+
+    for response in scraper {
+        let _data = response.expect("Pulling query failed.");
+    }
+}
+
+// Opt-in live-network tests. These hit the real Steam API, so they're flaky and
+// break when an appid is delisted; run with `cargo test -- --ignored`. Point a
+// `FixtureTransport::record` at `fixtures()` to refresh the saved pages.
+#[test]
+#[ignore = "hits live Steam"]
+fn live_test_app() {
+    let mut query: ReviewScraper = ReviewApi::new(APPLICATION)
+        .try_into()
+        .expect("Building basic query failed.");
+    let _response = query
+        .next()
+        .expect("Expected to pull data but our response is empty.")
+        .expect("Querying data failed");
+}
+
+#[test]
+#[ignore = "hits live Steam"]
+fn live_full_scrape() {
     let scraper: ReviewScraper = ReviewApi::new(LOW_REVS)
         .num_per_page(100)
         .try_into()